@@ -193,12 +193,35 @@ Classes
    SamplerV2
    Session
    Batch
+   SessionState
+   PagedList
    IBMBackend
    RuntimeJob
    RuntimeJobV2
    RuntimeOptions
    RuntimeEncoder
    RuntimeDecoder
+   MitigationMetadata
+   QueueLengthSampler
+   FleetMonitor
+   FleetEvent
+   BackendBenchmarks
+   InstanceGroup
+   HeavyHexTopology
+   PayloadCache
+
+Functions
+=========
+.. autosummary::
+   :toctree: ../stubs/
+
+   sampler_result_to_arrow
+   sampler_result_to_parquet
+   properties_dataframe
+   gate_properties_dataframe
+   mitigate_counts
+   enable_payload_cache
+   disable_payload_cache
 """
 
 import logging
@@ -210,6 +233,18 @@ from .runtime_job import RuntimeJob
 from .runtime_job_v2 import RuntimeJobV2
 from .runtime_options import RuntimeOptions
 from .utils.json import RuntimeEncoder, RuntimeDecoder
+from .utils.result_export import sampler_result_to_arrow, sampler_result_to_parquet
+from .utils.properties_export import properties_dataframe, gate_properties_dataframe
+from .utils.mitigation_metadata import MitigationMetadata
+from .utils.queue_history import QueueLengthSampler
+from .utils.fleet_events import FleetEvent, FleetMonitor
+from .utils.readout_mitigation import mitigate_counts
+from .utils.benchmarks import BackendBenchmarks
+from .utils.hgp import InstanceGroup
+from .utils.session_state import SessionState
+from .utils.paged_list import PagedList
+from .utils.heavy_hex import HeavyHexTopology
+from .utils.payload_cache import PayloadCache, enable_payload_cache, disable_payload_cache
 from .session import Session  # pylint: disable=cyclic-import
 from .batch import Batch  # pylint: disable=cyclic-import
 
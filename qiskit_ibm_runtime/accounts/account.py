@@ -14,7 +14,8 @@
 
 from abc import abstractmethod
 import logging
-from typing import Optional, Literal
+import re
+from typing import Any, Dict, Optional, Literal
 from urllib.parse import urlparse
 
 from requests.auth import AuthBase
@@ -22,7 +23,7 @@ from ..proxies import ProxyConfiguration
 from ..utils.hgp import from_instance_format
 
 from .exceptions import InvalidAccountError, CloudResourceNameResolutionError
-from ..api.auth import QuantumAuth, CloudAuth
+from ..api.auth import QuantumAuth, CloudAuth, TrustedProfileAuth
 from ..utils import resolve_crn
 
 AccountType = Optional[Literal["cloud", "legacy"]]
@@ -30,8 +31,13 @@ ChannelType = Optional[Literal["ibm_cloud", "ibm_quantum"]]
 
 IBM_QUANTUM_API_URL = "https://auth.quantum-computing.ibm.com/api"
 IBM_CLOUD_API_URL = "https://cloud.ibm.com"
+# Program IDs of the primitives, by which the default options are keyed.
+PRIMITIVE_IDS = ("sampler", "estimator")
 logger = logging.getLogger(__name__)
 
+# Tokens and CRNs are sent in HTTP headers, which only accept visible ASCII characters.
+RE_HEADER_VALUE = re.compile(r"^[\x21-\x7e]+$")
+
 
 class Account:
     """Class that represents an account. This is an abstract class."""
@@ -43,6 +49,10 @@ class Account:
         proxies: Optional[ProxyConfiguration] = None,
         verify: Optional[bool] = True,
         channel_strategy: Optional[str] = None,
+        option_profiles: Optional[Dict[str, Dict[str, Any]]] = None,
+        default_backend: Optional[str] = None,
+        default_options: Optional[Dict[str, Any]] = None,
+        trusted_profile: Optional[str] = None,
     ):
         """Account constructor.
 
@@ -54,6 +64,11 @@ class Account:
             proxies: Proxy configuration.
             verify: Whether to verify server's TLS certificate.
             channel_strategy: Error mitigation strategy.
+            option_profiles: Named sets of primitive options.
+            default_backend: Name of the backend used when none is specified.
+            default_options: Primitive options used when none are specified, keyed by
+                primitive, ``sampler`` or ``estimator``.
+            trusted_profile: IBM Cloud trusted profile to authenticate with instead of a token.
         """
         self.channel: str = None
         self.url: str = None
@@ -63,6 +78,10 @@ class Account:
         self.verify = verify
         self.channel_strategy = channel_strategy
         self.private_endpoint: bool = False
+        self.option_profiles = option_profiles
+        self.default_backend = default_backend
+        self.default_options = default_options
+        self.trusted_profile = trusted_profile
 
     def to_saved_format(self) -> dict:
         """Returns a dictionary that represents how the account is saved on disk."""
@@ -83,6 +102,10 @@ class Account:
         verify = data.get("verify", True)
         channel_strategy = data.get("channel_strategy")
         private_endpoint = data.get("private_endpoint", False)
+        option_profiles = data.get("option_profiles")
+        default_backend = data.get("default_backend")
+        default_options = data.get("default_options")
+        trusted_profile = data.get("trusted_profile")
         return cls.create_account(
             channel=channel,
             url=url,
@@ -92,6 +115,10 @@ class Account:
             verify=verify,
             channel_strategy=channel_strategy,
             private_endpoint=private_endpoint,
+            option_profiles=option_profiles,
+            default_backend=default_backend,
+            default_options=default_options,
+            trusted_profile=trusted_profile,
         )
 
     @classmethod
@@ -105,9 +132,18 @@ class Account:
         verify: Optional[bool] = True,
         channel_strategy: Optional[str] = None,
         private_endpoint: Optional[bool] = False,
+        option_profiles: Optional[Dict[str, Dict[str, Any]]] = None,
+        default_backend: Optional[str] = None,
+        default_options: Optional[Dict[str, Any]] = None,
+        trusted_profile: Optional[str] = None,
     ) -> "Account":
         """Creates an account for a specific channel."""
         if channel == "ibm_quantum":
+            if trusted_profile:
+                raise InvalidAccountError(
+                    "Invalid `trusted_profile` value. Trusted profiles are only supported "
+                    "on the ibm_cloud channel."
+                )
             return QuantumAccount(
                 url=url,
                 token=token,
@@ -115,6 +151,9 @@ class Account:
                 proxies=proxies,
                 verify=verify,
                 channel_strategy=channel_strategy,
+                option_profiles=option_profiles,
+                default_backend=default_backend,
+                default_options=default_options,
             )
         elif channel == "ibm_cloud":
             return CloudAccount(
@@ -125,6 +164,10 @@ class Account:
                 verify=verify,
                 channel_strategy=channel_strategy,
                 private_endpoint=private_endpoint,
+                option_profiles=option_profiles,
+                default_backend=default_backend,
+                default_options=default_options,
+                trusted_profile=trusted_profile,
             )
         else:
             raise InvalidAccountError(
@@ -149,6 +192,10 @@ class Account:
                 self.instance == other.instance,
                 self.proxies == other.proxies,
                 self.verify == other.verify,
+                self.option_profiles == other.option_profiles,
+                self.default_backend == other.default_backend,
+                self.default_options == other.default_options,
+                self.trusted_profile == other.trusted_profile,
             ]
         )
 
@@ -163,11 +210,15 @@ class Account:
         """
 
         self._assert_valid_channel(self.channel)
-        self._assert_valid_token(self.token)
+        if self.trusted_profile is None:
+            self._assert_valid_token(self.token)
+        self._assert_valid_trusted_profile(self.trusted_profile, self.token)
         self._assert_valid_url(self.url)
         self._assert_valid_instance(self.instance)
         self._assert_valid_proxies(self.proxies)
         self._assert_valid_channel_strategy(self.channel_strategy)
+        self._assert_valid_option_profiles(self.option_profiles)
+        self._assert_valid_defaults(self.default_backend, self.default_options)
         return self
 
     @staticmethod
@@ -181,6 +232,43 @@ class Account:
                 f"{strategies}, got '{channel_strategy}'."
             )
 
+    @staticmethod
+    def _assert_valid_option_profiles(option_profiles: Optional[Dict[str, Any]]) -> None:
+        """Assert that the option profiles map names to dictionaries of options."""
+        if option_profiles is None:
+            return
+        if not isinstance(option_profiles, dict) or not all(
+            isinstance(name, str) and isinstance(options, dict)
+            for name, options in option_profiles.items()
+        ):
+            raise InvalidAccountError(
+                "Invalid `option_profiles` value. Expected a dictionary mapping profile "
+                f"names to dictionaries of options, got '{option_profiles}'."
+            )
+
+    @staticmethod
+    def _assert_valid_defaults(
+        default_backend: Optional[str], default_options: Optional[Dict[str, Any]]
+    ) -> None:
+        """Assert that the default backend is a name and the default options are keyed by
+        primitive."""
+        if default_backend is not None and not (
+            isinstance(default_backend, str) and default_backend
+        ):
+            raise InvalidAccountError(
+                "Invalid `default_backend` value. Expected a backend name, "
+                f"got '{default_backend}'."
+            )
+        if default_options is not None and not (
+            isinstance(default_options, dict)
+            and set(default_options) <= set(PRIMITIVE_IDS)
+            and all(isinstance(options, dict) for options in default_options.values())
+        ):
+            raise InvalidAccountError(
+                "Invalid `default_options` value. Expected a dictionary of options for each "
+                f"primitive, keyed by {list(PRIMITIVE_IDS)}, got '{default_options}'."
+            )
+
     @staticmethod
     def _assert_valid_channel(channel: ChannelType) -> None:
         """Assert that the channel parameter is valid."""
@@ -197,6 +285,27 @@ class Account:
             raise InvalidAccountError(
                 f"Invalid `token` value. Expected a non-empty string, got '{token}'."
             )
+        if not RE_HEADER_VALUE.match(token):
+            raise InvalidAccountError(
+                "Invalid `token` value. It contains whitespace, control or non-ASCII "
+                "characters. Check that no spaces or line breaks were copied with it."
+            )
+
+    @staticmethod
+    def _assert_valid_trusted_profile(trusted_profile: Optional[str], token: str) -> None:
+        """Assert that the trusted profile is a non-empty string, not given with a token."""
+        if trusted_profile is None:
+            return
+        if not (isinstance(trusted_profile, str) and trusted_profile):
+            raise InvalidAccountError(
+                "Invalid `trusted_profile` value. Expected the ID or name of an IBM Cloud "
+                f"trusted profile, got '{trusted_profile}'."
+            )
+        if token:
+            raise InvalidAccountError(
+                "Invalid `trusted_profile` value. A trusted profile replaces the token, "
+                "so both cannot be given."
+            )
 
     @staticmethod
     def _assert_valid_url(url: str) -> None:
@@ -230,6 +339,9 @@ class QuantumAccount(Account):
         proxies: Optional[ProxyConfiguration] = None,
         verify: Optional[bool] = True,
         channel_strategy: Optional[str] = None,
+        option_profiles: Optional[Dict[str, Dict[str, Any]]] = None,
+        default_backend: Optional[str] = None,
+        default_options: Optional[Dict[str, Any]] = None,
     ):
         """Account constructor.
 
@@ -240,8 +352,21 @@ class QuantumAccount(Account):
             proxies: Proxy configuration.
             verify: Whether to verify server's TLS certificate.
             channel_strategy: Error mitigation strategy.
+            option_profiles: Named sets of primitive options.
+            default_backend: Name of the backend used when none is specified.
+            default_options: Primitive options used when none are specified, keyed by
+                primitive, ``sampler`` or ``estimator``.
         """
-        super().__init__(token, instance, proxies, verify, channel_strategy)
+        super().__init__(
+            token,
+            instance,
+            proxies,
+            verify,
+            channel_strategy,
+            option_profiles,
+            default_backend,
+            default_options,
+        )
         resolved_url = url or IBM_QUANTUM_API_URL
         self.channel = "ibm_quantum"
         self.url = resolved_url
@@ -274,6 +399,10 @@ class CloudAccount(Account):
         verify: Optional[bool] = True,
         channel_strategy: Optional[str] = None,
         private_endpoint: Optional[bool] = False,
+        option_profiles: Optional[Dict[str, Dict[str, Any]]] = None,
+        default_backend: Optional[str] = None,
+        default_options: Optional[Dict[str, Any]] = None,
+        trusted_profile: Optional[str] = None,
     ):
         """Account constructor.
 
@@ -285,8 +414,24 @@ class CloudAccount(Account):
             verify: Whether to verify server's TLS certificate.
             channel_strategy: Error mitigation strategy.
             private_endpoint: Connect to private API URL.
+            option_profiles: Named sets of primitive options.
+            default_backend: Name of the backend used when none is specified.
+            default_options: Primitive options used when none are specified, keyed by
+                primitive, ``sampler`` or ``estimator``.
+            trusted_profile: ID or name of the IBM Cloud trusted profile to authenticate
+                with, from a workload running in IBM Cloud, instead of an API key.
         """
-        super().__init__(token, instance, proxies, verify, channel_strategy)
+        super().__init__(
+            token,
+            instance,
+            proxies,
+            verify,
+            channel_strategy,
+            option_profiles,
+            default_backend,
+            default_options,
+            trusted_profile,
+        )
         resolved_url = url or IBM_CLOUD_API_URL
         self.channel = "ibm_cloud"
         self.url = resolved_url
@@ -294,6 +439,8 @@ class CloudAccount(Account):
 
     def get_auth_handler(self) -> AuthBase:
         """Returns the Cloud authentication handler."""
+        if self.trusted_profile:
+            return TrustedProfileAuth(self.trusted_profile, crn=self.instance, url=self.url)
         return CloudAuth(api_key=self.token, crn=self.instance)
 
     def resolve_crn(self) -> None:
@@ -310,6 +457,7 @@ class CloudAccount(Account):
             url=self.url,
             token=self.token,
             instance=self.instance,
+            trusted_profile=self.trusted_profile,
         )
         if len(crn) == 0:
             raise CloudResourceNameResolutionError(
@@ -336,3 +484,8 @@ class CloudAccount(Account):
                 "If using the ibm_quantum channel,",
                 "please specify the channel when saving your account with `channel = 'ibm_quantum'`.",
             )
+        if instance.startswith("crn:") and not RE_HEADER_VALUE.match(instance):
+            raise InvalidAccountError(
+                "Invalid `instance` value. The CRN contains whitespace, control or non-ASCII "
+                f"characters, got {instance!r}."
+            )
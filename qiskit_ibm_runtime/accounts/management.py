@@ -13,7 +13,7 @@
 """Account management related classes and functions."""
 
 import os
-from typing import Optional, Dict
+from typing import Any, Optional, Dict
 
 from ..proxies import ProxyConfiguration
 from .exceptions import AccountNotFoundError
@@ -48,6 +48,10 @@ class AccountManager:
         channel_strategy: Optional[str] = None,
         set_as_default: Optional[bool] = None,
         private_endpoint: Optional[bool] = False,
+        option_profiles: Optional[Dict[str, Dict[str, Any]]] = None,
+        default_backend: Optional[str] = None,
+        default_options: Optional[Dict[str, Any]] = None,
+        trusted_profile: Optional[str] = None,
     ) -> None:
         """Save account on disk."""
         channel = channel or os.getenv("QISKIT_IBM_CHANNEL") or _DEFAULT_CHANNEL_TYPE
@@ -63,6 +67,10 @@ class AccountManager:
             verify=verify,
             channel_strategy=channel_strategy,
             private_endpoint=private_endpoint,
+            option_profiles=option_profiles,
+            default_backend=default_backend,
+            default_options=default_options,
+            trusted_profile=trusted_profile,
         )
         return save_config(
             filename=filename,
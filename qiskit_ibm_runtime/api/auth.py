@@ -18,6 +18,8 @@ from typing import Dict
 from requests import PreparedRequest
 from requests.auth import AuthBase
 
+from ..utils.utils import get_cloud_authenticator
+
 
 class CloudAuth(AuthBase):
     """Attaches IBM Cloud Authentication to the given Request object."""
@@ -45,6 +47,35 @@ class CloudAuth(AuthBase):
         return {"Service-CRN": self.crn, "Authorization": f"apikey {self.api_key}"}
 
 
+class TrustedProfileAuth(AuthBase):
+    """Attaches IBM Cloud trusted profile authentication to the given Request object.
+
+    The compute resource token of the workload, such as a Kubernetes pod or a Code Engine
+    job running in IBM Cloud, is exchanged for an IAM access token of the trusted profile.
+    The access token is refreshed before it expires, so no long-lived API key is needed.
+    """
+
+    def __init__(self, trusted_profile: str, crn: str, url: str):
+        self.trusted_profile = trusted_profile
+        self.crn = crn
+        self._authenticator = get_cloud_authenticator(url, trusted_profile=trusted_profile)
+
+    def __eq__(self, other: object) -> bool:
+        if isinstance(other, TrustedProfileAuth):
+            return self.trusted_profile == other.trusted_profile and self.crn == other.crn
+        return False
+
+    def __call__(self, r: PreparedRequest) -> PreparedRequest:
+        r.headers.update(self.get_headers())
+        return r
+
+    def get_headers(self) -> Dict:
+        """Return authorization information to be stored in header."""
+        headers = {"Service-CRN": self.crn}
+        self._authenticator.authenticate({"headers": headers})
+        return headers
+
+
 class QuantumAuth(AuthBase):
     """Attaches IBM Quantum Authentication to the given Request object."""
 
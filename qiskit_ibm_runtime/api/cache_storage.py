@@ -0,0 +1,182 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Storage backends for the cache of backend configurations and properties."""
+
+import hashlib
+import json
+import math
+import os
+import threading
+import time
+from abc import ABC, abstractmethod
+from typing import Any, Dict, Optional, Tuple
+
+BACKEND_CONFIGURATION_TTL = 24 * 60 * 60
+"""Time, in seconds, for which a cached backend configuration is reused."""
+BACKEND_PROPERTIES_TTL = 15 * 60
+"""Time, in seconds, for which cached backend properties are reused."""
+
+
+class CacheStorage(ABC):
+    """Storage of cached API responses.
+
+    The values are JSON-serializable API responses, such as backend configurations and
+    properties. Storages shared between processes, like :class:`DiskCacheStorage` and
+    :class:`RedisCacheStorage`, let several workers reuse the responses fetched by one.
+    """
+
+    @abstractmethod
+    def get(self, key: str) -> Optional[Any]:
+        """Return a cached value.
+
+        Args:
+            key: Key of the value.
+
+        Returns:
+            The value, or ``None`` if it is not cached or has expired.
+        """
+
+    @abstractmethod
+    def set(self, key: str, value: Any, ttl: Optional[float] = None) -> None:
+        """Cache a value.
+
+        Args:
+            key: Key of the value.
+            value: JSON-serializable value.
+            ttl: Time, in seconds, after which the value expires. By default, it does
+                not expire.
+        """
+
+    @abstractmethod
+    def delete(self, key: str) -> None:
+        """Remove a cached value, if it is cached.
+
+        Args:
+            key: Key of the value.
+        """
+
+
+class MemoryCacheStorage(CacheStorage):
+    """Cache storage in the memory of the process. This is the default storage."""
+
+    def __init__(self) -> None:
+        self._values: Dict[str, Tuple[Any, Optional[float]]] = {}
+        self._lock = threading.Lock()
+
+    def get(self, key: str) -> Optional[Any]:
+        with self._lock:
+            value, expires_at = self._values.get(key, (None, None))
+            if expires_at is not None and expires_at <= time.monotonic():
+                del self._values[key]
+                return None
+            return value
+
+    def set(self, key: str, value: Any, ttl: Optional[float] = None) -> None:
+        expires_at = time.monotonic() + ttl if ttl is not None else None
+        with self._lock:
+            self._values[key] = (value, expires_at)
+
+    def delete(self, key: str) -> None:
+        with self._lock:
+            self._values.pop(key, None)
+
+
+class DiskCacheStorage(CacheStorage):
+    """Cache storage in JSON files, shared by the processes of a host."""
+
+    def __init__(self, directory: str) -> None:
+        """DiskCacheStorage constructor.
+
+        Args:
+            directory: Directory to store the values in. It is created if needed.
+        """
+        os.makedirs(directory, exist_ok=True)
+        self._directory = directory
+
+    def _path(self, key: str) -> str:
+        """Return the path of the file storing a value."""
+        return os.path.join(self._directory, f"{hashlib.sha256(key.encode()).hexdigest()}.json")
+
+    def get(self, key: str) -> Optional[Any]:
+        try:
+            with open(self._path(key), encoding="utf-8") as file:
+                entry = json.load(file)
+            expires_at, value = entry["expires_at"], entry["value"]
+        except (OSError, ValueError, KeyError, TypeError):
+            # A missing, partial or malformed entry is a cache miss.
+            return None
+        if expires_at is not None and expires_at <= time.time():
+            self.delete(key)
+            return None
+        return value
+
+    def set(self, key: str, value: Any, ttl: Optional[float] = None) -> None:
+        entry = {"expires_at": time.time() + ttl if ttl is not None else None, "value": value}
+        # Write to a temporary file first, so that other processes never read a
+        # partially written value.
+        temp_path = f"{self._path(key)}.{os.getpid()}.{threading.get_ident()}.tmp"
+        with open(temp_path, "w", encoding="utf-8") as file:
+            json.dump(entry, file)
+        os.replace(temp_path, self._path(key))
+
+    def delete(self, key: str) -> None:
+        try:
+            os.remove(self._path(key))
+        except FileNotFoundError:
+            pass
+
+
+class RedisCacheStorage(CacheStorage):
+    """Cache storage in a Redis server, shared by the processes of a deployment.
+
+    This storage requires the ``redis`` package.
+    """
+
+    def __init__(
+        self,
+        url: str = "redis://localhost:6379/0",
+        client: Optional[Any] = None,
+        prefix: str = "qiskit_ibm_runtime:",
+    ) -> None:
+        """RedisCacheStorage constructor.
+
+        Args:
+            url: URL of the Redis server.
+            client: Redis client to use instead of connecting to ``url``.
+            prefix: Prefix of the Redis keys of the values.
+
+        Raises:
+            ImportError: If ``client`` is not given and ``redis`` is not installed.
+        """
+        if client is None:
+            try:
+                import redis
+            except ImportError as ex:
+                raise ImportError(
+                    "The redis package is required to use RedisCacheStorage. "
+                    "You can install it with 'pip install redis'."
+                ) from ex
+            client = redis.Redis.from_url(url)
+        self._client = client
+        self._prefix = prefix
+
+    def get(self, key: str) -> Optional[Any]:
+        value = self._client.get(self._prefix + key)
+        return json.loads(value) if value is not None else None
+
+    def set(self, key: str, value: Any, ttl: Optional[float] = None) -> None:
+        expires_in = math.ceil(ttl) if ttl is not None else None
+        self._client.set(self._prefix + key, json.dumps(value), ex=expires_in)
+
+    def delete(self, key: str) -> None:
+        self._client.delete(self._prefix + key)
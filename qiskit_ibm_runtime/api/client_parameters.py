@@ -12,11 +12,13 @@
 
 """Represent IBM Quantum account client parameters."""
 
-from typing import Dict, Optional, Any, Union
+from typing import Dict, List, Optional, Any, Union
 from ..proxies import ProxyConfiguration
 
 from ..utils import get_runtime_api_base_url
-from ..api.auth import QuantumAuth, CloudAuth
+from ..api.auth import QuantumAuth, CloudAuth, TrustedProfileAuth
+from ..api.session import RequestHook, ResponseHook, Resolver
+from ..api.cache_storage import CacheStorage
 
 TEMPLATE_IBM_HUBS = "{prefix}/Network/{hub}/Groups/{group}/Projects/{project}"
 """str: Template for creating an IBM Quantum URL with hub/group/project information."""
@@ -34,6 +36,14 @@ class ClientParameters:
         proxies: Optional[ProxyConfiguration] = None,
         verify: bool = True,
         private_endpoint: Optional[bool] = False,
+        request_hooks: Optional[List[RequestHook]] = None,
+        response_hooks: Optional[List[ResponseHook]] = None,
+        ip_version: Optional[str] = None,
+        resolver: Optional[Resolver] = None,
+        exposed_headers: Optional[List[str]] = None,
+        cache_storage: Optional[CacheStorage] = None,
+        trusted_profile: Optional[str] = None,
+        compression_threshold: Optional[int] = None,
     ) -> None:
         """ClientParameters constructor.
 
@@ -45,6 +55,16 @@ class ClientParameters:
             proxies: Proxy configuration.
             verify: If ``False``, ignores SSL certificates errors.
             private_endpoint: Connect to private API URL.
+            request_hooks: Callables invoked before every request is sent.
+            response_hooks: Callables invoked with every response received.
+            ip_version: Preferred IP version to connect with, ``ipv4`` or ``ipv6``.
+            resolver: Callable returning the IP addresses of a host name.
+            exposed_headers: Names of the response headers exposed to the user.
+            cache_storage: Storage of the cached backend configurations and properties.
+            trusted_profile: IBM Cloud trusted profile to authenticate with instead of
+                an API key.
+            compression_threshold: Size, in bytes, above which request bodies are
+                compressed with gzip.
         """
         self.token = token
         self.instance = instance
@@ -53,9 +73,19 @@ class ClientParameters:
         self.proxies = proxies
         self.verify = verify
         self.private_endpoint = private_endpoint
+        self.request_hooks = request_hooks or []
+        self.response_hooks = response_hooks or []
+        self.ip_version = ip_version
+        self.resolver = resolver
+        self.exposed_headers = exposed_headers
+        self.cache_storage = cache_storage
+        self.trusted_profile = trusted_profile
+        self.compression_threshold = compression_threshold
 
-    def get_auth_handler(self) -> Union[CloudAuth, QuantumAuth]:
+    def get_auth_handler(self) -> Union[CloudAuth, TrustedProfileAuth, QuantumAuth]:
         """Returns the respective authentication handler."""
+        if self.channel == "ibm_cloud" and self.trusted_profile:
+            return TrustedProfileAuth(self.trusted_profile, crn=self.instance, url=self.url)
         if self.channel == "ibm_cloud":
             return CloudAuth(api_key=self.token, crn=self.instance)
 
@@ -71,11 +101,24 @@ class ClientParameters:
         Returns:
             A dictionary with connection-related parameters in the format
             expected by ``requests``. The following keys can be present:
-            ``proxies``, ``verify``, and ``auth``.
+            ``proxies``, ``verify``, ``auth``, ``request_hooks``, ``response_hooks``,
+            ``ip_version``, ``resolver``, ``exposed_headers`` and ``compression_threshold``.
         """
         request_kwargs: Any = {"verify": self.verify}
 
         if self.proxies:
             request_kwargs.update(self.proxies.to_request_params())
+        if self.request_hooks:
+            request_kwargs["request_hooks"] = self.request_hooks
+        if self.response_hooks:
+            request_kwargs["response_hooks"] = self.response_hooks
+        if self.ip_version:
+            request_kwargs["ip_version"] = self.ip_version
+        if self.resolver:
+            request_kwargs["resolver"] = self.resolver
+        if self.exposed_headers:
+            request_kwargs["exposed_headers"] = self.exposed_headers
+        if self.compression_threshold is not None:
+            request_kwargs["compression_threshold"] = self.compression_threshold
 
         return request_kwargs
@@ -12,14 +12,94 @@
 
 """Client for accessing IBM Quantum authentication services."""
 
-from typing import Dict, List, Optional, Any, Union
+import hashlib
+import logging
+import threading
+from datetime import datetime, timedelta
+from typing import Callable, Dict, List, Optional, Any, Tuple, Union
 from requests.exceptions import RequestException
 
 from ..auth import QuantumAuth
 from ..exceptions import AuthenticationLicenseError, RequestsApiError
+from ...exceptions import IBMNotAuthorizedError
 from ..rest import Api
 from ..session import RetrySession
 from ..client_parameters import ClientParameters
+from ...utils.converters import str_to_utc
+
+logger = logging.getLogger(__name__)
+
+TOKEN_REFRESH_MARGIN = 300
+"""Number of seconds before the access token expires at which it is considered expiring."""
+
+_CachedToken = Tuple[str, Optional[datetime]]
+
+
+class _AccessTokenCache:
+    """Access tokens shared by all the clients that use the same API token and URL.
+
+    Only a hash of the API token is kept. Clients requesting a token for the same key
+    concurrently wait for a single login request.
+    """
+
+    def __init__(self) -> None:
+        self._tokens: Dict[Tuple[str, str], _CachedToken] = {}
+        self._locks: Dict[Tuple[str, str], threading.Lock] = {}
+        self._lock = threading.Lock()
+
+    @staticmethod
+    def key(api_token: str, url: str) -> Tuple[str, str]:
+        """Return the cache key of an API token and authentication URL."""
+        return hashlib.sha256(api_token.encode("utf-8")).hexdigest(), url
+
+    def get(
+        self,
+        key: Tuple[str, str],
+        request_token: Callable[[], _CachedToken],
+        is_valid: Callable[[_CachedToken], bool],
+    ) -> _CachedToken:
+        """Return the cached token for a key, requesting a new one if needed.
+
+        Args:
+            key: Cache key, as returned by :meth:`key`.
+            request_token: Callable returning a new token and its expiration time.
+            is_valid: Callable returning whether a cached token can be reused.
+
+        Returns:
+            The access token and its expiration time.
+        """
+        with self._lock:
+            key_lock = self._locks.setdefault(key, threading.Lock())
+        with key_lock:
+            cached = self._tokens.get(key)
+            if cached is not None and is_valid(cached):
+                return cached
+            token = request_token()
+            self._tokens[key] = token
+            return token
+
+    def evict(self, key: Tuple[str, str], access_token: str) -> None:
+        """Remove a cached token that the server rejected.
+
+        The token is only removed if it is still the cached one, so that a token already
+        renewed by another client is kept.
+
+        Args:
+            key: Cache key, as returned by :meth:`key`.
+            access_token: The rejected access token.
+        """
+        with self._lock:
+            cached = self._tokens.get(key)
+            if cached is not None and cached[0] == access_token:
+                del self._tokens[key]
+
+    def clear(self) -> None:
+        """Remove all the cached tokens."""
+        with self._lock:
+            self._tokens.clear()
+
+
+_ACCESS_TOKEN_CACHE = _AccessTokenCache()
 
 
 class AuthClient:
@@ -34,6 +114,8 @@ class AuthClient:
         self.api_token = client_params.token
         self.auth_url = client_params.url
         self._service_urls = {}  # type: ignore[var-annotated]
+        self._user_id: Optional[str] = None
+        self._access_token_expiry: Optional[datetime] = None
 
         self.auth_api = Api(RetrySession(self.auth_url, **client_params.connection_parameters()))
         self.base_api = self._init_service_clients(**client_params.connection_parameters())
@@ -50,7 +132,9 @@ class AuthClient:
         # Request an access token.
         self.access_token = self._request_access_token()
         self.auth_api.session.auth = QuantumAuth(access_token=self.access_token)
-        self._service_urls = self.user_urls()
+        user_info = self._with_reauthentication(self.auth_api.user_info)
+        self._service_urls = user_info["urls"]
+        self._user_id = user_info.get("id")
 
         # Create the api server client, using the access token.
         base_api = Api(
@@ -64,10 +148,35 @@ class AuthClient:
         return base_api
 
     def _request_access_token(self) -> str:
+        """Return an access token for the API token.
+
+        Access tokens are shared by the clients that use the same API token and
+        authentication URL. A new one is requested from the API authentication
+        service if there is no cached token, or if it is expiring.
+
+        Returns:
+            An access token.
+        """
+        self.access_token, self._access_token_expiry = _ACCESS_TOKEN_CACHE.get(
+            _AccessTokenCache.key(self.api_token, self.auth_url),
+            self._login,
+            self._is_token_valid,
+        )
+        return self.access_token
+
+    def _is_token_valid(self, token: _CachedToken) -> bool:
+        """Return whether a cached access token can be reused."""
+        expiry = token[1]
+        if expiry is None:
+            return True
+        ttl = (expiry - self.auth_api.session.server_time()).total_seconds()
+        return ttl > TOKEN_REFRESH_MARGIN
+
+    def _login(self) -> _CachedToken:
         """Request a new access token from the API authentication service.
 
         Returns:
-            A new access token.
+            A new access token and its expiration time.
 
         Raises:
             AuthenticationLicenseError: If the user hasn't accepted the license agreement.
@@ -75,7 +184,7 @@ class AuthClient:
         """
         try:
             response = self.auth_api.login(self.api_token)
-            return response["id"]
+            return response["id"], self._token_expiry(response)
         except RequestsApiError as ex:
             # Get the original exception that raised.
             original_exception = ex.__cause__
@@ -97,8 +206,36 @@ class AuthClient:
                         pass
             raise
 
+    def _token_expiry(self, login_response: Dict[str, Any]) -> Optional[datetime]:
+        """Return the expiration time of an access token, according to the server clock.
+
+        Args:
+            login_response: Response of the login request.
+
+        Returns:
+            The expiration time of the access token, or ``None`` if its TTL is not reported.
+        """
+        ttl = login_response.get("ttl")
+        if ttl is None:
+            return None
+        try:
+            created = str_to_utc(login_response.get("created"))
+        except ValueError:
+            created = None
+        # The creation time is set by the server, so it does not need to be corrected
+        # for clock skew. Otherwise, the TTL counts from the current server time.
+        return (created or self.auth_api.session.server_time()) + timedelta(seconds=ttl)
+
     # User account-related public functions.
 
+    def current_user_id(self) -> Optional[str]:
+        """Return the ID of the authenticated user.
+
+        Returns:
+            ID of the user the access token was issued to, if reported by the server.
+        """
+        return self._user_id
+
     def user_urls(self) -> Dict[str, str]:
         """Retrieve the API URLs from the authentication service.
 
@@ -110,7 +247,7 @@ class AuthClient:
                 * ``ws``: The API URL for websocket communication.
                 * ``services`: The API URL for additional services.
         """
-        response = self.auth_api.user_info()
+        response = self._with_reauthentication(self.auth_api.user_info)
         return response["urls"]
 
     def user_hubs(self) -> List[Dict[str, str]]:
@@ -123,7 +260,7 @@ class AuthClient:
             A list of dictionaries with the hub, group, and project values keyed by
             ``hub``, ``group``, and ``project``, respectively.
         """
-        response = self.base_api.hubs()
+        response = self._with_reauthentication(self.base_api.hubs)
 
         hubs = []  # type: ignore[var-annotated]
         for hub in response:
@@ -155,13 +292,83 @@ class AuthClient:
         return self.base_api.version()
 
     def current_access_token(self) -> Optional[str]:
-        """Return the current access token.
+        """Return the current access token, refreshing it first if it is expiring.
 
         Returns:
             The access token in use.
         """
+        if self.access_token_expiring():
+            self._refresh_access_token()
         return self.access_token
 
+    def _refresh_access_token(self) -> None:
+        """Request a new access token and use it for the following requests."""
+        self._request_access_token()
+        self.auth_api.session.auth = QuantumAuth(access_token=self.access_token)
+        # The service API client does not exist yet while the client is initialized.
+        if getattr(self, "base_api", None) is not None:
+            self.base_api.session.auth = QuantumAuth(access_token=self.access_token)
+
+    def _with_reauthentication(self, request: Callable[[], Any]) -> Any:
+        """Send a request, authenticating again once if the access token is rejected.
+
+        A shared access token can be revoked before its expiration time, for example when
+        the user logs out elsewhere. It is then removed from the shared cache, so that the
+        other clients do not reuse it either.
+
+        Args:
+            request: Callable sending the request.
+
+        Returns:
+            The result of the request.
+        """
+        try:
+            return request()
+        except (IBMNotAuthorizedError, RequestsApiError) as ex:
+            if isinstance(ex, RequestsApiError) and ex.status_code != 401:
+                raise
+            logger.debug("The access token was rejected. Authenticating again.")
+            _ACCESS_TOKEN_CACHE.evict(
+                _AccessTokenCache.key(self.api_token, self.auth_url), self.access_token
+            )
+            self._refresh_access_token()
+            return request()
+
+    def access_token_ttl(self) -> Optional[float]:
+        """Return the number of seconds until the access token expires.
+
+        The remaining time is computed against the server clock, estimated from the
+        ``Date`` header of the responses, so that a skewed local clock does not cause
+        the token to be considered expired too early or too late.
+
+        Returns:
+            Number of seconds until the access token expires, or ``None`` if unknown.
+        """
+        if self._access_token_expiry is None:
+            return None
+        return (self._access_token_expiry - self.auth_api.session.server_time()).total_seconds()
+
+    def access_token_expiring(self, margin: float = TOKEN_REFRESH_MARGIN) -> bool:
+        """Return whether the access token expires within ``margin`` seconds.
+
+        A warning is logged if the access token is expiring.
+
+        Args:
+            margin: Number of seconds before the expiration of the token at which
+                it is considered expiring.
+
+        Returns:
+            Whether the access token should be refreshed.
+        """
+        ttl = self.access_token_ttl()
+        if ttl is None or ttl > margin:
+            return False
+        if ttl <= 0:
+            logger.warning("The access token has expired.")
+        else:
+            logger.warning("The access token expires in %d seconds.", ttl)
+        return True
+
     def current_service_urls(self) -> Dict:
         """Return the current service URLs.
 
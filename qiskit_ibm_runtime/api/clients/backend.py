@@ -37,13 +37,17 @@ class BaseBackendClient(ABC):
 
     @abstractmethod
     def backend_properties(
-        self, backend_name: str, datetime: Optional[python_datetime] = None
+        self,
+        backend_name: str,
+        datetime: Optional[python_datetime] = None,
+        refresh: bool = False,
     ) -> Dict[str, Any]:
         """Return the properties of the backend.
 
         Args:
             backend_name: The name of the backend.
             datetime: Date and time for additional filtering of backend properties.
+            refresh: If ``True``, bypass the cached properties.
 
         Returns:
             Backend properties.
@@ -25,6 +25,7 @@ import enum
 from websocket import WebSocketApp, STATUS_NORMAL, STATUS_ABNORMAL_CLOSED
 
 from ..client_parameters import ClientParameters
+from ..session import proxy_env_enabled
 from ..exceptions import WebsocketTimeoutError
 
 logger = logging.getLogger(__name__)
@@ -64,6 +65,9 @@ class BaseWebsocketClient(ABC):
         self._proxy_params = (
             client_params.proxies.to_ws_params(self._websocket_url) if client_params.proxies else {}
         )
+        if "http_proxy_host" not in self._proxy_params and not proxy_env_enabled():
+            # Stop the websocket client from reading proxies from the environment.
+            self._proxy_params["http_no_proxy"] = ["*"]
         self._access_token = client_params.token
         self._job_id = job_id
         self._message_queue = message_queue
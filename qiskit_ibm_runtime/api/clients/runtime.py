@@ -13,14 +13,26 @@
 """Client for accessing IBM Quantum runtime service."""
 
 import logging
+from concurrent.futures import ThreadPoolExecutor
 from typing import Any, Dict, List, Optional
 from datetime import datetime as python_datetime
 from requests import Response
 
 from qiskit_ibm_runtime.api.session import RetrySession
+from qiskit_ibm_runtime.api.cache_storage import (
+    BACKEND_CONFIGURATION_TTL,
+    BACKEND_PROPERTIES_TTL,
+    MemoryCacheStorage,
+)
 
 from .backend import BaseBackendClient
 from ..rest.runtime import Runtime
+from ..rest.utils.data_mapper import (
+    map_job_response,
+    map_jobs_response,
+    map_session_response,
+    map_sessions_response,
+)
 from ..client_parameters import ClientParameters
 from ...utils.hgp import from_instance_format
 
@@ -45,7 +57,13 @@ class RuntimeClient(BaseBackendClient):
             **params.connection_parameters(),
         )
         self._api = Runtime(self._session)
-        self._configuration_registry: Dict[str, Dict[str, Any]] = {}
+        self._cache = params.cache_storage or MemoryCacheStorage()
+        # Properties change with every calibration, so they are only cached in a
+        # storage that was configured explicitly.
+        self._cache_properties = params.cache_storage is not None
+        # Instances can see different configurations of the same backend, so the cache
+        # keys include the instance in case the storage is shared.
+        self._cache_instance = params.instance or ""
 
     def program_run(
         self,
@@ -111,7 +129,7 @@ class RuntimeClient(BaseBackendClient):
         """
         response = self._api.program_job(job_id).get(exclude_params=exclude_params)
         logger.debug("Runtime job get response: %s", response)
-        return response
+        return map_job_response(response)
 
     def jobs_get(
         self,
@@ -155,7 +173,7 @@ class RuntimeClient(BaseBackendClient):
         Returns:
             JSON response.
         """
-        return self._api.jobs_get(
+        response = self._api.jobs_get(
             limit=limit,
             skip=skip,
             backend_name=backend_name,
@@ -170,6 +188,7 @@ class RuntimeClient(BaseBackendClient):
             created_before=created_before,
             descending=descending,
         )
+        return map_jobs_response(response)
 
     def job_results(self, job_id: str) -> str:
         """Get the results of a program job.
@@ -260,6 +279,15 @@ class RuntimeClient(BaseBackendClient):
         """Update session so jobs can no longer be submitted."""
         self._api.runtime_session(session_id=session_id).close()
 
+    def update_session(self, session_id: str, accepting_jobs: bool) -> None:
+        """Update whether new jobs can be submitted to the session.
+
+        Args:
+            session_id: Session ID.
+            accepting_jobs: Whether new jobs can be submitted.
+        """
+        self._api.runtime_session(session_id=session_id).update(accepting_jobs)
+
     def session_details(self, session_id: str) -> Dict[str, Any]:
         """Get session details.
 
@@ -269,7 +297,30 @@ class RuntimeClient(BaseBackendClient):
         Returns:
             Session details.
         """
-        return self._api.runtime_session(session_id=session_id).details()
+        return map_session_response(self._api.runtime_session(session_id=session_id).details())
+
+    def sessions_get(
+        self,
+        limit: Optional[int] = None,
+        skip: Optional[int] = None,
+        pending: Optional[bool] = None,
+        backend_name: Optional[str] = None,
+    ) -> Dict[str, Any]:
+        """Get the details of the sessions of the instance.
+
+        Args:
+            limit: Number of results to return.
+            skip: Number of results to skip.
+            pending: Returns open sessions if True, closed sessions if False.
+            backend_name: Name of the backend of the sessions.
+
+        Returns:
+            JSON response, with the sessions and their total count.
+        """
+        response = self._api.runtime_session(session_id=None).list(
+            limit=limit, skip=skip, pending=pending, backend_name=backend_name
+        )
+        return map_sessions_response(response)
 
     def list_backends(
         self, hgp: Optional[str] = None, channel_strategy: Optional[str] = None
@@ -285,6 +336,49 @@ class RuntimeClient(BaseBackendClient):
         """
         return self._api.backends(hgp=hgp, channel_strategy=channel_strategy)["devices"]
 
+    def warm_up(self) -> None:
+        """Set up the connection to the server ahead of the first real request.
+
+        This resolves the server host name, performs the TLS handshake and validates
+        the credentials, leaving an open connection in the session pool so the first
+        latency-sensitive call does not pay the connection setup cost.
+        """
+        self._api.backends()
+
+    def server_time(self) -> python_datetime:
+        """Return the current time according to the server clock.
+
+        Returns:
+            The current UTC time, adjusted for the clock skew observed in the responses.
+        """
+        return self._session.server_time()
+
+    def last_response_headers(self) -> Dict[str, str]:
+        """Return the exposed headers of the last response received by this client.
+
+        Returns:
+            The headers, such as the rate limit and request ID headers, keyed by
+            lowercase name.
+        """
+        return dict(self._session.last_response_headers)
+
+    def metrics_text(self) -> str:
+        """Return the metrics of the requests sent by this client.
+
+        Returns:
+            Request counts and latencies per endpoint, in the Prometheus text
+            exposition format.
+        """
+        return self._session.metrics.to_prometheus()
+
+    def usage(self) -> Dict[str, Any]:
+        """Return the usage of the instance in the current usage period.
+
+        Returns:
+            Usage of the instance.
+        """
+        return self._api.usage()
+
     def is_qctrl_enabled(self) -> bool:
         """Returns a boolean of whether or not the instance has q-ctrl enabled.
 
@@ -302,11 +396,35 @@ class RuntimeClient(BaseBackendClient):
         Returns:
             Backend configuration.
         """
-        if backend_name not in self._configuration_registry:
-            self._configuration_registry[backend_name] = self._api.backend(
-                backend_name
-            ).configuration()
-        return self._configuration_registry[backend_name].copy()
+        key = self._cache_key(backend_name, "configuration")
+        configuration = self._cache.get(key)
+        if configuration is None:
+            configuration = self._api.backend(backend_name).configuration()
+            self._cache.set(key, configuration, ttl=BACKEND_CONFIGURATION_TTL)
+        return configuration.copy()
+
+    def _cache_key(self, backend_name: str, resource: str) -> str:
+        """Return the key of a cached backend resource."""
+        return f"{self._session.base_url}|{self._cache_instance}|{backend_name}/{resource}"
+
+    def backend_configurations(
+        self, backend_names: List[str], max_workers: int = 8
+    ) -> Dict[str, Dict[str, Any]]:
+        """Return the configurations of several IBM backends.
+
+        The configurations are fetched concurrently, and cached like those returned by
+        :meth:`backend_configuration`.
+
+        Args:
+            backend_names: The names of the IBM backends.
+            max_workers: Maximum number of configurations fetched concurrently.
+
+        Returns:
+            The backend configurations, keyed by backend name.
+        """
+        with ThreadPoolExecutor(max_workers=max_workers) as executor:
+            configurations = executor.map(self.backend_configuration, backend_names)
+            return dict(zip(backend_names, configurations))
 
     def backend_status(self, backend_name: str) -> Dict[str, Any]:
         """Return the status of the IBM backend.
@@ -320,13 +438,17 @@ class RuntimeClient(BaseBackendClient):
         return self._api.backend(backend_name).status()
 
     def backend_properties(
-        self, backend_name: str, datetime: Optional[python_datetime] = None
+        self,
+        backend_name: str,
+        datetime: Optional[python_datetime] = None,
+        refresh: bool = False,
     ) -> Dict[str, Any]:
         """Return the properties of the IBM backend.
 
         Args:
             backend_name: The name of the IBM backend.
             datetime: Date and time for additional filtering of backend properties.
+            refresh: If ``True``, bypass the cached properties.
 
         Returns:
             Backend properties.
@@ -334,7 +456,37 @@ class RuntimeClient(BaseBackendClient):
         Raises:
             NotImplementedError: If `datetime` is specified.
         """
-        return self._api.backend(backend_name).properties(datetime=datetime)
+        if datetime or not self._cache_properties:
+            return self._api.backend(backend_name).properties(datetime=datetime)
+        key = self._cache_key(backend_name, "properties")
+        properties = None if refresh else self._cache.get(key)
+        if properties is None:
+            properties = self._api.backend(backend_name).properties()
+            if properties:
+                self._cache.set(key, properties, ttl=BACKEND_PROPERTIES_TTL)
+        return properties
+
+    def backend_snapshot(self, backend_name: str) -> Dict[str, Any]:
+        """Return the configuration, properties and status of the IBM backend.
+
+        The three are fetched concurrently, which takes about as long as the slowest of
+        the requests instead of their sum.
+
+        Args:
+            backend_name: The name of the IBM backend.
+
+        Returns:
+            The ``configuration``, ``properties`` and ``status`` of the backend.
+        """
+        with ThreadPoolExecutor(max_workers=3) as executor:
+            configuration = executor.submit(self.backend_configuration, backend_name)
+            properties = executor.submit(self.backend_properties, backend_name)
+            status = executor.submit(self.backend_status, backend_name)
+            return {
+                "configuration": configuration.result(),
+                "properties": properties.result(),
+                "status": status.result(),
+            }
 
     def backend_pulse_defaults(self, backend_name: str) -> Dict:
         """Return the pulse defaults of the IBM backend.
@@ -12,6 +12,8 @@
 
 """Exceptions related to the IBM Quantum API."""
 
+from typing import Optional
+
 from ..exceptions import IBMError
 
 
@@ -35,6 +37,38 @@ class RequestsApiError(ApiError):
         self.status_code = status_code
 
 
+class ForbiddenApiError(RequestsApiError):
+    """Exception raised when the account is not authorized to perform a request (403)."""
+
+    def __init__(self, message: str, error_code: Optional[str] = None):
+        """ForbiddenApiError constructor.
+
+        Args:
+            message: Exception message.
+            error_code: Code of the error returned by the server, if any.
+        """
+        super().__init__(message, 403)
+        self.error_code = error_code
+
+
+class UnexpectedContentTypeError(RequestsApiError):
+    """Exception raised when a response is not JSON, e.g. an HTML page from a proxy."""
+
+    pass
+
+
+class ResultIntegrityError(ApiError):
+    """Exception raised when a downloaded payload does not match its checksum."""
+
+    pass
+
+
+class RequestSchemaError(ApiError):
+    """Exception raised when a request body does not match the request schemas of the client."""
+
+    pass
+
+
 class WebsocketError(ApiError):
     """Exceptions related to websockets."""
 
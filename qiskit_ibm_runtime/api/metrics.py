@@ -0,0 +1,155 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Client-side metrics of the requests sent to the API."""
+
+import re
+import threading
+from typing import Dict, List, Mapping, Optional, Tuple
+
+METRICS_PREFIX = "qiskit_ibm_runtime"
+LATENCY_BUCKETS = (0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0)
+"""Upper bounds, in seconds, of the request latency histogram buckets."""
+
+# Matches the resource ID following a collection in a URL path, e.g. the job ID in
+# `/jobs/<job_id>/results`, so that the metrics are not labeled per resource.
+RE_RESOURCE_ID = re.compile(r"/(jobs|sessions|backends|programs|instances)/[^/]+")
+
+_Labels = Tuple[str, str]
+
+
+def normalize_endpoint(path: str) -> str:
+    """Replace the resource IDs of a URL path with placeholders.
+
+    Args:
+        path: URL path, e.g. ``/jobs/abc123/results``.
+
+    Returns:
+        The normalized path, e.g. ``/jobs/{id}/results``.
+    """
+    return RE_RESOURCE_ID.sub(r"/\1/{id}", path)
+
+
+def _escape(value: str) -> str:
+    """Escape a label value for the Prometheus text format."""
+    return value.replace("\\", "\\\\").replace('"', '\\"').replace("\n", "\\n")
+
+
+class _Histogram:
+    """Latency histogram of a single endpoint."""
+
+    def __init__(self) -> None:
+        self.buckets = [0] * len(LATENCY_BUCKETS)
+        self.count = 0
+        self.sum = 0.0
+
+    def observe(self, value: float) -> None:
+        """Record a value."""
+        for index, bound in enumerate(LATENCY_BUCKETS):
+            if value <= bound:
+                self.buckets[index] += 1
+        self.count += 1
+        self.sum += value
+
+
+class RequestMetrics:
+    """Request counts and latencies, per endpoint.
+
+    The metrics can be exported in the Prometheus text exposition format with
+    :meth:`to_prometheus`.
+    """
+
+    def __init__(self) -> None:
+        self._counts: Dict[Tuple[str, str, str], int] = {}
+        self._latencies: Dict[_Labels, _Histogram] = {}
+        self._phases: Dict[Tuple[str, str, str], _Histogram] = {}
+        self._lock = threading.Lock()
+
+    def observe(
+        self,
+        method: str,
+        path: str,
+        status_code: int,
+        duration: float,
+        phases: Optional[Mapping[str, Optional[float]]] = None,
+    ) -> None:
+        """Record a request.
+
+        Args:
+            method: Method of the request.
+            path: URL path of the request.
+            status_code: Status code of the response, or -1 if there was no response.
+            duration: Time taken by the request, in seconds.
+            phases: Time taken by the phases of the request, such as ``connect``,
+                ``tls`` and ``ttfb``, in seconds. Phases that did not happen are ``None``.
+        """
+        labels = (method.upper(), normalize_endpoint(path))
+        with self._lock:
+            key = labels + (str(status_code),)
+            self._counts[key] = self._counts.get(key, 0) + 1
+            self._latencies.setdefault(labels, _Histogram()).observe(duration)
+            for phase, value in (phases or {}).items():
+                if value is not None and phase != "total":
+                    self._phases.setdefault(labels + (phase,), _Histogram()).observe(value)
+
+    def to_prometheus(self) -> str:
+        """Return the metrics in the Prometheus text exposition format.
+
+        Returns:
+            The metrics, with the request counts per method, endpoint and status code
+            in ``qiskit_ibm_runtime_requests_total``, and the request latencies per
+            method and endpoint in ``qiskit_ibm_runtime_request_duration_seconds``.
+            The durations of the request phases, such as the connection, the TLS
+            handshake and the wait for the first byte of the response, are in
+            ``qiskit_ibm_runtime_request_phase_duration_seconds``.
+        """
+        requests_total = f"{METRICS_PREFIX}_requests_total"
+        duration = f"{METRICS_PREFIX}_request_duration_seconds"
+        phase_duration = f"{METRICS_PREFIX}_request_phase_duration_seconds"
+        lines: List[str] = [
+            f"# HELP {requests_total} Number of requests sent to the API.",
+            f"# TYPE {requests_total} counter",
+        ]
+        with self._lock:
+            for (method, endpoint, status), count in sorted(self._counts.items()):
+                lines.append(
+                    f'{requests_total}{{method="{method}",endpoint="{_escape(endpoint)}",'
+                    f'status="{status}"}} {count}'
+                )
+            lines.append(f"# HELP {duration} Latency of the requests sent to the API.")
+            lines.append(f"# TYPE {duration} histogram")
+            for (method, endpoint), histogram in sorted(self._latencies.items()):
+                labels = f'method="{method}",endpoint="{_escape(endpoint)}"'
+                for bound, count in zip(LATENCY_BUCKETS, histogram.buckets):
+                    lines.append(f'{duration}_bucket{{{labels},le="{bound}"}} {count}')
+                lines.append(f'{duration}_bucket{{{labels},le="+Inf"}} {histogram.count}')
+                lines.append(f"{duration}_sum{{{labels}}} {histogram.sum}")
+                lines.append(f"{duration}_count{{{labels}}} {histogram.count}")
+            lines.append(f"# HELP {phase_duration} Duration of the phases of the requests.")
+            lines.append(f"# TYPE {phase_duration} histogram")
+            for (method, endpoint, phase), histogram in sorted(self._phases.items()):
+                labels = f'method="{method}",endpoint="{_escape(endpoint)}",phase="{phase}"'
+                for bound, count in zip(LATENCY_BUCKETS, histogram.buckets):
+                    lines.append(f'{phase_duration}_bucket{{{labels},le="{bound}"}} {count}')
+                lines.append(f'{phase_duration}_bucket{{{labels},le="+Inf"}} {histogram.count}')
+                lines.append(f"{phase_duration}_sum{{{labels}}} {histogram.sum}")
+                lines.append(f"{phase_duration}_count{{{labels}}} {histogram.count}")
+        return "\n".join(lines) + "\n"
+
+    def __getstate__(self) -> Dict:
+        state = self.__dict__.copy()
+        state.pop("_lock")
+        return state
+
+    def __setstate__(self, state: Dict) -> None:
+        self.__dict__.update(state)
+        self._lock = threading.Lock()
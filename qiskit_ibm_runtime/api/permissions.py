@@ -0,0 +1,57 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Guidance for the authentication and authorization errors returned by the API."""
+
+from typing import Dict, Optional
+
+UNAUTHENTICATED_GUIDANCE = (
+    "The credentials were rejected. Check that the token or API key is valid and has not "
+    "expired, and that it belongs to the channel and URL of the account."
+)
+
+# Where to look for the missing access, keyed by channel. The API does not report which
+# permission a request lacks, so the guidance is the same for all the requests of a channel.
+FORBIDDEN_GUIDANCE: Dict[str, str] = {
+    "ibm_cloud": "check that the API key has an IAM role granting access to the service "
+    "instance (CRN) of the account.",
+    "ibm_quantum": "check that the instance (hub/group/project) has access to the "
+    "requested resource, such as the backend.",
+}
+
+ERROR_CODES_URL = "https://docs.quantum.ibm.com/errors"
+
+
+def forbidden_guidance(channel: Optional[str], error_code: Optional[str] = None) -> str:
+    """Return guidance for a request that the account is not authorized to perform.
+
+    Args:
+        channel: Channel of the account, ``ibm_cloud`` or ``ibm_quantum``, if known.
+        error_code: Code of the error returned by the server, if any.
+
+    Returns:
+        The guidance for the channel, pointing to the description of the error code.
+    """
+    if channel in FORBIDDEN_GUIDANCE:
+        checks = FORBIDDEN_GUIDANCE[channel]
+    else:
+        checks = " ".join(
+            f"On {'IBM Cloud' if name == 'ibm_cloud' else 'IBM Quantum'}, {guidance}"
+            for name, guidance in FORBIDDEN_GUIDANCE.items()
+        )
+    guidance = (
+        "The credentials are valid, but the account is not authorized to perform this "
+        f"request. {checks[0].upper()}{checks[1:]}"
+    )
+    if error_code:
+        guidance += f" The error code {error_code} is described at {ERROR_CODES_URL}."
+    return guidance
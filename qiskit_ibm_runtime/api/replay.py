@@ -0,0 +1,156 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Recording and offline replay of API exchanges, to reproduce deserialization issues."""
+
+import itertools
+import json
+import os
+import threading
+from datetime import datetime, timezone
+from typing import Any, Dict, Mapping, Optional, Type, Union
+from urllib.parse import urlparse
+
+from requests import Response
+
+from ..utils.json import RuntimeDecoder
+from ..utils.result_decoder import ResultDecoder
+
+REDACTED = "<redacted>"
+SENSITIVE_HEADERS = {
+    "authorization",
+    "x-access-token",
+    "cookie",
+    "set-cookie",
+    "proxy-authorization",
+}
+SENSITIVE_FIELDS = {"apitoken", "api_token", "apikey", "access_token", "refresh_token", "token"}
+
+
+def _sanitize_headers(headers: Mapping[str, str]) -> Dict[str, str]:
+    """Return the headers, with the values of credential headers redacted."""
+    return {
+        name: REDACTED if name.lower() in SENSITIVE_HEADERS else value
+        for name, value in headers.items()
+    }
+
+
+def _sanitize_value(value: Any) -> Any:
+    """Return a JSON value, with the values of credential fields redacted."""
+    if isinstance(value, dict):
+        return {
+            key: REDACTED if key.lower() in SENSITIVE_FIELDS else _sanitize_value(item)
+            for key, item in value.items()
+        }
+    if isinstance(value, list):
+        return [_sanitize_value(item) for item in value]
+    return value
+
+
+def _sanitize_body(body: Optional[Union[str, bytes]]) -> Optional[str]:
+    """Return a request or response body, with the values of credential fields redacted."""
+    if body is None:
+        return None
+    if isinstance(body, bytes):
+        body = body.decode("utf-8", errors="replace")
+    try:
+        return json.dumps(_sanitize_value(json.loads(body)))
+    except ValueError:
+        return body
+
+
+class ExchangeRecorder:
+    """Response hook that saves API exchanges to disk.
+
+    Each exchange is saved as a JSON file with the method, path, headers and body of the
+    request and the status code, headers and body of the response. Credentials, in
+    headers or in JSON bodies, and query strings are never saved, so the files can be
+    attached to an issue report and replayed offline with :func:`replay_exchange`.
+
+    Example::
+
+        service = QiskitRuntimeService(response_hooks=[ExchangeRecorder("exchanges")])
+    """
+
+    def __init__(self, directory: str, errors_only: bool = True) -> None:
+        """ExchangeRecorder constructor.
+
+        Args:
+            directory: Directory to save the exchanges in. It is created if needed.
+            errors_only: Whether to only save the exchanges whose response has an error
+                status code. Set to ``False`` to also save successful responses, for
+                example to reproduce a response that cannot be deserialized.
+        """
+        os.makedirs(directory, exist_ok=True)
+        self._directory = directory
+        self._errors_only = errors_only
+        self._counter = itertools.count()
+        self._lock = threading.Lock()
+
+    def __call__(self, response: Response) -> None:
+        """Save the exchange of a response."""
+        if self._errors_only and response.ok:
+            return
+        request = response.request
+        exchange = {
+            "recorded": datetime.now(timezone.utc).isoformat(),
+            "request": {
+                "method": request.method,
+                "path": urlparse(request.url).path,
+                "headers": _sanitize_headers(request.headers),
+                "body": _sanitize_body(request.body),
+            },
+            "response": {
+                "status_code": response.status_code,
+                "headers": _sanitize_headers(response.headers),
+                "body": _sanitize_body(response.content),
+            },
+        }
+        with self._lock:
+            index = next(self._counter)
+        file_name = f"{index:05d}-{request.method}-{response.status_code}.json"
+        with open(os.path.join(self._directory, file_name), "w", encoding="utf-8") as file:
+            json.dump(exchange, file, indent=2)
+
+
+def load_exchange(path: str) -> Dict[str, Any]:
+    """Load an exchange saved by :class:`ExchangeRecorder`.
+
+    Args:
+        path: Path of the saved exchange.
+
+    Returns:
+        The exchange, with ``request`` and ``response`` entries.
+    """
+    with open(path, encoding="utf-8") as file:
+        return json.load(file)
+
+
+def replay_exchange(path: str, decoder: Optional[Type[ResultDecoder]] = None) -> Any:
+    """Deserialize the response body of a saved exchange, without credentials.
+
+    Example::
+
+        result = replay_exchange("exchanges/00003-GET-200.json", decoder=SamplerResultDecoder)
+
+    Args:
+        path: Path of the saved exchange.
+        decoder: Result decoder to use, for job results. By default, the body is
+            deserialized with :class:`~qiskit_ibm_runtime.utils.json.RuntimeDecoder`.
+
+    Returns:
+        The deserialized response body.
+    """
+    body = load_exchange(path)["response"]["body"]
+    if decoder is not None:
+        return decoder.decode(body)
+    return json.loads(body, cls=RuntimeDecoder)
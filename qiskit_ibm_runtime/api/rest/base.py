@@ -12,7 +12,36 @@
 
 """Base REST adapter."""
 
+import re
+from urllib.parse import quote
+
 from ..session import RetrySession
+from ...exceptions import IBMInputValueError
+
+RE_CONTROL_CHARACTERS = re.compile(r"[\x00-\x1f\x7f]")
+
+
+def quote_path_segment(value: str) -> str:
+    """Return a value percent-encoded for use as a single URL path segment.
+
+    Identifiers such as backend names, job IDs and session IDs are inserted in the URL
+    path. Characters like ``/``, ``?``, ``#``, ``%`` and non-ASCII characters would
+    otherwise change the requested path or produce a malformed URL.
+
+    Args:
+        value: Value of the path segment.
+
+    Returns:
+        The percent-encoded value.
+
+    Raises:
+        IBMInputValueError: If the value is empty, ``.`` or ``..``, or contains control characters.
+    """
+    if not isinstance(value, str) or value in ("", ".", ".."):
+        raise IBMInputValueError(f"Invalid URL path segment: {value!r}.")
+    if RE_CONTROL_CHARACTERS.search(value):
+        raise IBMInputValueError(f"URL path segment {value!r} contains control characters.")
+    return quote(value, safe="")
 
 
 class RestAdapterBase:
@@ -15,7 +15,7 @@
 from typing import Dict, Any, Optional
 from datetime import datetime as python_datetime
 
-from qiskit_ibm_runtime.api.rest.base import RestAdapterBase
+from qiskit_ibm_runtime.api.rest.base import RestAdapterBase, quote_path_segment
 from ..session import RetrySession
 
 
@@ -38,7 +38,9 @@ class CloudBackend(RestAdapterBase):
             url_prefix: Base URL.
         """
         self.backend_name = backend_name
-        super().__init__(session, "{}/backends/{}".format(url_prefix, backend_name))
+        super().__init__(
+            session, "{}/backends/{}".format(url_prefix, quote_path_segment(backend_name))
+        )
 
     def configuration(self) -> Dict[str, Any]:
         """Return backend configuration.
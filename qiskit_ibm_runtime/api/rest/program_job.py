@@ -13,13 +13,18 @@
 """Program Job REST adapter."""
 
 import json
+import logging
 from typing import Dict
 from requests import Response
 
-from .base import RestAdapterBase
+from .base import RestAdapterBase, quote_path_segment
+from .utils.checksum import content_matches_checksum
+from ..exceptions import ResultIntegrityError
 from ..session import RetrySession
 from ...utils.json import RuntimeDecoder
 
+logger = logging.getLogger(__name__)
+
 
 class ProgramJob(RestAdapterBase):
     """Rest adapter for program job related endpoints."""
@@ -42,7 +47,7 @@ class ProgramJob(RestAdapterBase):
             job_id: ID of the program job.
             url_prefix: Prefix to use in the URL.
         """
-        super().__init__(session, "{}/jobs/{}".format(url_prefix, job_id))
+        super().__init__(session, "{}/jobs/{}".format(url_prefix, quote_path_segment(job_id)))
 
     def get(self, exclude_params: bool = None) -> Dict:
         """Return program job information.
@@ -74,10 +79,24 @@ class ProgramJob(RestAdapterBase):
     def results(self) -> str:
         """Return program job results.
 
+        If the server sends a ``Content-MD5`` header with the results, the downloaded
+        content is verified against it and downloaded again once on mismatch.
+
         Returns:
             Job results.
+
+        Raises:
+            ResultIntegrityError: If the results still do not match their checksum
+                after being downloaded again.
         """
         response = self.session.get(self.get_url("results"))
+        if not content_matches_checksum(response):
+            logger.warning("Job results do not match their checksum. Downloading them again.")
+            response = self.session.get(self.get_url("results"))
+            if not content_matches_checksum(response):
+                raise ResultIntegrityError(
+                    "The downloaded job results do not match the checksum sent by the server."
+                )
         return response.text
 
     def cancel(self) -> None:
@@ -13,8 +13,9 @@
 """Runtime REST adapter."""
 
 import logging
+import time
 from datetime import datetime
-from typing import Dict, Any, List, Union, Optional
+from typing import Any, Dict, List, Union, Optional
 import json
 
 from qiskit_ibm_runtime.api.rest.base import RestAdapterBase
@@ -24,6 +25,7 @@ from .runtime_session import RuntimeSession
 
 from ...utils import RuntimeEncoder
 from .cloud_backend import CloudBackend
+from .utils.request_builders import JobRequestBuilder
 
 logger = logging.getLogger(__name__)
 
@@ -36,6 +38,7 @@ class Runtime(RestAdapterBase):
         "jobs": "/jobs",
         "backends": "/backends",
         "cloud_instance": "/instance",
+        "usage": "/usage",
     }
 
     def program_job(self, job_id: str) -> "ProgramJob":
@@ -96,36 +99,42 @@ class Runtime(RestAdapterBase):
             channel_strategy: Error mitigation strategy.
 
         Returns:
-            JSON response.
+            JSON response, with the ``submission_stats`` of the encoded payload.
         """
         url = self.get_url("jobs")
-        payload: Dict[str, Any] = {
-            "program_id": program_id,
-            "params": params,
-        }
-        if image:
-            payload["runtime"] = image
-        if log_level:
-            payload["log_level"] = log_level
-        if backend_name:
-            payload["backend"] = backend_name
-        if session_id:
-            payload["session_id"] = session_id
-        if job_tags:
-            payload["tags"] = job_tags
-        if max_execution_time:
-            payload["cost"] = max_execution_time
+        builder = (
+            JobRequestBuilder(program_id=program_id, params=params)
+            .image(image)
+            .log_level(log_level)
+            .backend(backend_name)
+            .session(session_id)
+            .tags(job_tags)
+            .max_execution_time(max_execution_time)
+            .instance(hub, group, project)
+            .channel_strategy(channel_strategy)
+        )
         if start_session:
-            payload["start_session"] = start_session
-            payload["session_time"] = session_time
-        if all([hub, group, project]):
-            payload["hub"] = hub
-            payload["group"] = group
-            payload["project"] = project
-        if channel_strategy:
-            payload["channel_strategy"] = channel_strategy
-        data = json.dumps(payload, cls=RuntimeEncoder)
-        return self.session.post(url, data=data, timeout=900).json()
+            builder.start_session(session_time)
+        payload = builder.build()
+        encoder = RuntimeEncoder()
+        start = time.perf_counter()
+        data = encoder.encode(payload)
+        submission_stats = {
+            "num_pubs": len(params.get("pubs", [])) if isinstance(params, dict) else 0,
+            "num_circuits": encoder.num_circuits,
+            "circuit_bytes": encoder.circuit_bytes,
+            "encoded_circuit_bytes": encoder.encoded_circuit_bytes,
+            "payload_bytes": len(data),
+            "encoding_time": time.perf_counter() - start,
+        }
+        logger.debug(
+            "Encoded %d PUBs (%d circuits, %d bytes compressed to %d) into a %d-byte "
+            "payload in %.3f seconds.",
+            *submission_stats.values(),
+        )
+        response = self.session.post(url, data=data, timeout=900).json()
+        response["submission_stats"] = submission_stats
+        return response
 
     def jobs_get(
         self,
@@ -231,6 +240,16 @@ class Runtime(RestAdapterBase):
             params["channel_strategy"] = channel_strategy
         return self.session.get(url, params=params, timeout=timeout).json()
 
+    def usage(self) -> Dict[str, Any]:
+        """Return the usage of the instance in the current usage period.
+
+        This endpoint is only available for IBM Cloud instances.
+
+        Returns:
+            Usage of the instance.
+        """
+        return self.session.get(self.get_url("usage")).json()
+
     def is_qctrl_enabled(self) -> bool:
         """Return boolean of whether or not the instance has q-ctrl enabled.
 
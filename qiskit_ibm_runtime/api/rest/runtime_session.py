@@ -12,8 +12,9 @@
 
 """Runtime Session REST adapter."""
 
-from typing import Dict, Any, Optional
-from .base import RestAdapterBase
+from typing import Dict, Any, Optional, Union
+from .base import RestAdapterBase, quote_path_segment
+from .utils.request_builders import SessionRequestBuilder
 from ..session import RetrySession
 from ..exceptions import RequestsApiError
 from ...exceptions import IBMRuntimeError
@@ -38,7 +39,9 @@ class RuntimeSession(RestAdapterBase):
         if not session_id:
             super().__init__(session, "{}/sessions".format(url_prefix))
         else:
-            super().__init__(session, "{}/sessions/{}".format(url_prefix, session_id))
+            super().__init__(
+                session, "{}/sessions/{}".format(url_prefix, quote_path_segment(session_id))
+            )
 
     def create(
         self,
@@ -50,20 +53,45 @@ class RuntimeSession(RestAdapterBase):
     ) -> Dict[str, Any]:
         """Create a session"""
         url = self.get_url("self")
-        payload = {}
-        if mode:
-            payload["mode"] = mode
-        if backend:
-            payload["backend"] = backend
-        if instance:
-            payload["instance"] = instance
-        if max_time:
-            if channel == "ibm_quantum":
-                payload["max_session_ttl"] = max_time  # type: ignore[assignment]
-            else:
-                payload["max_ttl"] = max_time  # type: ignore[assignment]
+        payload = (
+            SessionRequestBuilder(channel=channel)
+            .mode(mode)
+            .backend(backend)
+            .instance(instance)
+            .max_time(max_time)
+            .build()
+        )
         return self.session.post(url, json=payload).json()
 
+    def list(
+        self,
+        limit: Optional[int] = None,
+        skip: Optional[int] = None,
+        pending: Optional[bool] = None,
+        backend_name: Optional[str] = None,
+    ) -> Dict[str, Any]:
+        """Return the sessions of the instance, newest first.
+
+        Args:
+            limit: Number of results to return.
+            skip: Number of results to skip.
+            pending: Returns open sessions if True, closed sessions if False.
+            backend_name: Name of the backend of the sessions.
+
+        Returns:
+            JSON response.
+        """
+        params: Dict[str, Union[int, str]] = {}
+        if limit:
+            params["limit"] = limit
+        if skip:
+            params["offset"] = skip
+        if pending is not None:
+            params["pending"] = "true" if pending else "false"
+        if backend_name:
+            params["backend"] = backend_name
+        return self.session.get(self.get_url("self"), params=params).json()
+
     def cancel(self) -> None:
         """Cancel all jobs in the session."""
         url = self.get_url("close")
@@ -71,16 +99,22 @@ class RuntimeSession(RestAdapterBase):
 
     def close(self) -> None:
         """Set accepting_jobs flag to false, so no more jobs can be submitted."""
-        payload = {"accepting_jobs": False}
-        url = self.get_url("self")
         try:
-            self.session.patch(url, json=payload)
+            self.update(accepting_jobs=False)
         except RequestsApiError as ex:
             if ex.status_code == 404:
                 pass
             else:
                 raise IBMRuntimeError(f"Error closing session: {ex}")
 
+    def update(self, accepting_jobs: bool) -> None:
+        """Set whether new jobs can be submitted to the session.
+
+        Args:
+            accepting_jobs: Whether new jobs can be submitted.
+        """
+        self.session.patch(self.get_url("self"), json={"accepting_jobs": accepting_jobs})
+
     def details(self) -> Dict[str, Any]:
         """Return the details of this session."""
         return self.session.get(self.get_url("self")).json()
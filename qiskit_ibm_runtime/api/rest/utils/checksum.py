@@ -0,0 +1,41 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Utility module for verifying payload checksums."""
+
+import base64
+import hashlib
+import sys
+
+from requests import Response
+
+
+def content_matches_checksum(response: Response) -> bool:
+    """Return whether the content of a response matches its ``Content-MD5`` header.
+
+    Args:
+        response: Response to verify.
+
+    Returns:
+        ``False`` if the response has a ``Content-MD5`` header that does not match
+        its content, ``True`` otherwise.
+    """
+    expected = response.headers.get("Content-MD5")
+    if not expected:
+        return True
+    if sys.version_info >= (3, 9):
+        # The digest only detects corrupted content, so it is allowed on FIPS systems.
+        digest = hashlib.md5(response.content, usedforsecurity=False).digest()
+    else:
+        digest = hashlib.md5(response.content).digest()
+    actual = base64.b64encode(digest).decode("ascii")
+    return actual == expected.strip()
@@ -14,7 +14,103 @@
 
 import re
 import keyword
-from typing import Dict, Any, Optional
+from typing import Dict, Any, Iterable, Optional
+
+# Fields of the API responses that are renamed from camelCase to snake_case if needed.
+# The API mixes both casings, e.g. the jobs limit endpoint returns ``maximumJobs``, so
+# these fields are read in either. Critical fields, like the IDs and states, are single
+# words and are never renamed.
+JOB_RESPONSE_FIELDS = (
+    "session_id",
+    "user_id",
+    "results_expiration",
+    "estimated_running_time_seconds",
+)
+JOB_STATE_FIELDS = ("reason_code",)
+SESSION_RESPONSE_FIELDS = (
+    "backend_name",
+    "interactive_ttl",
+    "max_ttl",
+    "active_ttl",
+    "accepting_jobs",
+    "last_job_started",
+    "last_job_completed",
+    "started_at",
+    "closed_at",
+    "activated_at",
+    "elapsed_time",
+    "user_id",
+)
+
+
+def map_job_response(data: Dict[str, Any]) -> Dict[str, Any]:
+    """Map job response data, tolerating camelCase field names.
+
+    Args:
+        data: Data to be mapped.
+
+    Returns:
+        Mapped data.
+    """
+    map_field_casing(data, JOB_RESPONSE_FIELDS)
+    if isinstance(data.get("state"), dict):
+        map_field_casing(data["state"], JOB_STATE_FIELDS)
+    return data
+
+
+def map_jobs_response(data: Dict[str, Any]) -> Dict[str, Any]:
+    """Map jobs list response data, tolerating camelCase field names.
+
+    Args:
+        data: Data to be mapped.
+
+    Returns:
+        Mapped data.
+    """
+    for job in data.get("jobs", []):
+        map_job_response(job)
+    return data
+
+
+def map_session_response(data: Dict[str, Any]) -> Dict[str, Any]:
+    """Map session response data, tolerating camelCase field names.
+
+    Args:
+        data: Data to be mapped.
+
+    Returns:
+        Mapped data.
+    """
+    map_field_casing(data, SESSION_RESPONSE_FIELDS)
+    return data
+
+
+def map_sessions_response(data: Dict[str, Any]) -> Dict[str, Any]:
+    """Map sessions list response data, tolerating camelCase field names.
+
+    Args:
+        data: Data to be mapped.
+
+    Returns:
+        Mapped data.
+    """
+    for session in data.get("sessions", []):
+        map_session_response(session)
+    return data
+
+
+def map_field_casing(data: Dict[str, Any], fields: Iterable[str]) -> None:
+    """Rename the camelCase keys of the given snake_case fields, if only they are present.
+
+    Args:
+        data: Dictionary to be converted.
+        fields: Names of the fields, in snake_case.
+    """
+    for field in fields:
+        head, *tail = field.split("_")
+        camel_case = head + "".join(word.capitalize() for word in tail)
+        if field not in data and camel_case in data:
+            data[field] = data.pop(camel_case)
 
 
 def map_jobs_limit_response(data: Dict[str, Any]) -> Dict[str, Any]:
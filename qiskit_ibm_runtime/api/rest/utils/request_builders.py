@@ -0,0 +1,175 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Builders for the bodies of job and session creation requests."""
+
+from typing import Any, Dict, List, Optional
+
+from ...exceptions import RequestSchemaError
+from .schema_validation import validate_request_body
+
+
+class JobRequestBuilder:
+    """Builder for the body of a job creation request.
+
+    Required fields are keyword-only constructor arguments. Optional fields are set with
+    chainable methods, so that fields of the same type cannot be swapped by position.
+
+    Example::
+
+        body = (
+            JobRequestBuilder(program_id="sampler", params=params)
+            .backend("ibm_kyoto")
+            .tags(["experiment"])
+            .build()
+        )
+    """
+
+    def __init__(self, *, program_id: str, params: Dict[str, Any]) -> None:
+        """JobRequestBuilder constructor.
+
+        Args:
+            program_id: Program ID.
+            params: Program parameters.
+
+        Raises:
+            RequestSchemaError: If a required field is missing.
+        """
+        if not program_id:
+            raise RequestSchemaError("A job request requires a program ID.")
+        if params is None:
+            raise RequestSchemaError("A job request requires program parameters.")
+        self._body: Dict[str, Any] = {"program_id": program_id, "params": params}
+
+    def backend(self, backend_name: Optional[str]) -> "JobRequestBuilder":
+        """Set the name of the backend to run the job on."""
+        return self._set("backend", backend_name)
+
+    def image(self, image: Optional[str]) -> "JobRequestBuilder":
+        """Set the runtime image."""
+        return self._set("runtime", image)
+
+    def log_level(self, log_level: Optional[str]) -> "JobRequestBuilder":
+        """Set the log level of the job."""
+        return self._set("log_level", log_level)
+
+    def session(self, session_id: Optional[str]) -> "JobRequestBuilder":
+        """Set the ID of the session the job belongs to."""
+        return self._set("session_id", session_id)
+
+    def tags(self, job_tags: Optional[List[str]]) -> "JobRequestBuilder":
+        """Set the tags of the job."""
+        return self._set("tags", job_tags)
+
+    def max_execution_time(self, seconds: Optional[int]) -> "JobRequestBuilder":
+        """Set the maximum execution time of the job, in seconds."""
+        return self._set("cost", seconds)
+
+    def start_session(self, session_time: Optional[int] = None) -> "JobRequestBuilder":
+        """Start a runtime session with the job.
+
+        Args:
+            session_time: Length of the session, in seconds.
+        """
+        self._body["start_session"] = True
+        self._body["session_time"] = session_time
+        return self
+
+    def instance(
+        self, hub: Optional[str], group: Optional[str], project: Optional[str]
+    ) -> "JobRequestBuilder":
+        """Set the hub, group and project of the job.
+
+        The instance is only set if all of ``hub``, ``group`` and ``project`` are given.
+        """
+        if all([hub, group, project]):
+            self._body.update({"hub": hub, "group": group, "project": project})
+        return self
+
+    def channel_strategy(self, channel_strategy: Optional[str]) -> "JobRequestBuilder":
+        """Set the channel strategy of the job."""
+        return self._set("channel_strategy", channel_strategy)
+
+    def build(self) -> Dict[str, Any]:
+        """Return the request body.
+
+        Returns:
+            The request body, validated against the ``job_create`` schema if
+            request validation is enabled.
+        """
+        body = dict(self._body)
+        validate_request_body("job_create", body)
+        return body
+
+    def _set(self, key: str, value: Any) -> "JobRequestBuilder":
+        """Set an optional field, if a value is given."""
+        if value:
+            self._body[key] = value
+        return self
+
+
+class SessionRequestBuilder:
+    """Builder for the body of a session creation request.
+
+    Example::
+
+        body = SessionRequestBuilder(channel="ibm_cloud").backend("ibm_kyoto").build()
+    """
+
+    def __init__(self, *, channel: Optional[str]) -> None:
+        """SessionRequestBuilder constructor.
+
+        Args:
+            channel: Channel of the account, which determines the name of some fields.
+        """
+        self._channel = channel
+        self._body: Dict[str, Any] = {}
+
+    def mode(self, mode: Optional[str]) -> "SessionRequestBuilder":
+        """Set the execution mode, ``dedicated`` or ``batch``."""
+        return self._set("mode", mode)
+
+    def backend(self, backend_name: Optional[str]) -> "SessionRequestBuilder":
+        """Set the name of the backend to create the session on."""
+        return self._set("backend", backend_name)
+
+    def instance(self, instance: Optional[str]) -> "SessionRequestBuilder":
+        """Set the instance of the session."""
+        return self._set("instance", instance)
+
+    def max_time(self, max_time: Optional[int]) -> "SessionRequestBuilder":
+        """Set the maximum time the session can be open, in seconds.
+
+        Raises:
+            RequestSchemaError: If ``max_time`` is not positive.
+        """
+        if max_time is not None and max_time <= 0:
+            raise RequestSchemaError("The maximum session time must be positive.")
+        key = "max_session_ttl" if self._channel == "ibm_quantum" else "max_ttl"
+        return self._set(key, max_time)
+
+    def build(self) -> Dict[str, Any]:
+        """Return the request body.
+
+        Returns:
+            The request body, validated against the ``session_create`` schema if
+            request validation is enabled.
+        """
+        body = dict(self._body)
+        validate_request_body("session_create", body)
+        return body
+
+    def _set(self, key: str, value: Any) -> "SessionRequestBuilder":
+        """Set an optional field, if a value is given."""
+        if value:
+            self._body[key] = value
+        return self
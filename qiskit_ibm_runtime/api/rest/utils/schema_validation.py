@@ -0,0 +1,87 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Utility module for validating request bodies against the API schemas.
+
+The schemas are maintained with the client, not generated from the API specification.
+They describe the fields the client sends and check their types, but allow fields they
+do not list, so that they do not reject bodies the server accepts.
+"""
+
+import json
+import logging
+import os
+from functools import lru_cache
+from typing import Any, Dict
+
+from ...exceptions import RequestSchemaError
+
+try:
+    import jsonschema
+
+    HAS_JSONSCHEMA = True
+except ImportError:
+    HAS_JSONSCHEMA = False
+
+VALIDATE_REQUESTS_ENV_VAR = "QISKIT_IBM_RUNTIME_VALIDATE_REQUESTS"
+SCHEMAS_DIR = os.path.join(os.path.dirname(os.path.dirname(__file__)), "schemas")
+
+logger = logging.getLogger(__name__)
+
+
+def validation_enabled() -> bool:
+    """Return whether request bodies should be validated.
+
+    Validation is a debug aid, enabled by setting the
+    ``QISKIT_IBM_RUNTIME_VALIDATE_REQUESTS`` environment variable to ``True``.
+    """
+    return os.getenv(VALIDATE_REQUESTS_ENV_VAR, "False") == "True"
+
+
+@lru_cache(maxsize=None)
+def load_schema(name: str) -> Dict[str, Any]:
+    """Load a request schema shipped with the package.
+
+    Args:
+        name: Name of the schema, e.g. ``job_create``.
+
+    Returns:
+        The JSON schema.
+    """
+    with open(os.path.join(SCHEMAS_DIR, f"{name}.json"), encoding="utf-8") as schema_file:
+        return json.load(schema_file)
+
+
+def validate_request_body(name: str, body: Dict[str, Any]) -> None:
+    """Validate a request body against a schema, if validation is enabled.
+
+    Args:
+        name: Name of the schema, e.g. ``job_create``.
+        body: Request body to validate.
+
+    Raises:
+        RequestSchemaError: If the body does not match the schema.
+    """
+    if not validation_enabled():
+        return
+    if not HAS_JSONSCHEMA:
+        logger.warning(
+            "Request validation is enabled but the 'jsonschema' package is not installed."
+        )
+        return
+    try:
+        jsonschema.validate(body, load_schema(name))
+    except jsonschema.ValidationError as ex:
+        field = ".".join(str(item) for item in ex.absolute_path) or "<root>"
+        raise RequestSchemaError(
+            f"Request body does not match the '{name}' schema at '{field}': {ex.message}"
+        ) from None
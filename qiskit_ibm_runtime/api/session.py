@@ -12,23 +12,40 @@
 
 """Session customized for IBM Quantum access."""
 
+import gzip
 import inspect
+import ipaddress
+import json
 import os
 import re
 import logging
+import socket
 import sys
-from typing import Dict, Optional, Any, Tuple, Union
+import threading
+import time
+from collections import deque
+from datetime import datetime, timedelta, timezone
+from email.utils import parsedate_to_datetime
+from typing import Callable, Deque, Dict, List, Optional, Any, Sequence, Tuple, Type, Union
 from pathlib import PurePath
+from urllib.parse import urlparse
 import importlib.metadata
 
 from requests import Session, RequestException, Response
+from requests.sessions import merge_setting
 from requests.adapters import HTTPAdapter
 from requests.auth import AuthBase
+from urllib3.connection import HTTPConnection, HTTPSConnection
+from urllib3.connectionpool import HTTPConnectionPool, HTTPSConnectionPool
+from urllib3.exceptions import ConnectTimeoutError
 from urllib3.util.retry import Retry
 
 from qiskit_ibm_runtime.utils.utils import filter_data
 
-from .exceptions import RequestsApiError
+from .auth import CloudAuth, QuantumAuth, TrustedProfileAuth
+from .exceptions import ForbiddenApiError, RequestsApiError, UnexpectedContentTypeError
+from .metrics import RequestMetrics
+from .permissions import UNAUTHENTICATED_GUIDANCE, forbidden_guidance
 from ..exceptions import IBMNotAuthorizedError
 from ..version import __version__ as ibm_runtime_version
 
@@ -45,6 +62,36 @@ STATUS_FORCELIST = (
 CUSTOM_HEADER_ENV_VAR = "QISKIT_IBM_RUNTIME_CUSTOM_CLIENT_APP_HEADER"
 QE_PROVIDER_HEADER_ENV_VAR = "QE_CUSTOM_CLIENT_APP_HEADER"
 USAGE_DATA_OPT_OUT_ENV_VAR = "USAGE_DATA_OPT_OUT"
+PROXY_ENV_OPT_OUT_ENV_VAR = "QISKIT_IBM_RUNTIME_IGNORE_PROXY_ENV"
+REQUEST_HISTORY_SIZE = 50
+REQUEST_ID_HEADERS = ("x-request-id", "x-global-transaction-id", "uber-trace-id")
+CONTENT_SNIPPET_LENGTH = 200
+"""Number of characters of an unexpected response body included in error messages."""
+EXPOSED_RESPONSE_HEADERS = (
+    "x-ratelimit-limit",
+    "x-ratelimit-remaining",
+    "x-ratelimit-reset",
+    "retry-after",
+    "x-request-id",
+    "x-global-transaction-id",
+    "ibm-api-version",
+)
+"""Response headers exposed by default through ``last_response_headers``."""
+RATE_LIMIT_PACING_THRESHOLD = 5
+"""Number of remaining requests in the rate limit window below which requests are paced."""
+RATE_LIMIT_MAX_DELAY = 60
+"""Maximum delay, in seconds, added before a request to stay within the rate limit."""
+COMPRESSION_MAX_RATIO = 0.9
+"""Compressed request bodies larger than this fraction of the original size are sent
+uncompressed."""
+ENCODED_PAYLOAD_MAX_FRACTION = 0.5
+"""Request bodies in which base64 payloads, like encoded circuits, make up more than this
+fraction of the size are sent without trying to compress them."""
+ENCODED_PAYLOAD_PATTERN = re.compile(rb"[A-Za-z0-9+/=]{256,}")
+"""Long base64 runs, such as zlib-compressed QPY circuits encoded by ``RuntimeEncoder``."""
+RATE_LIMIT_EPOCH_RESET = 10**9
+"""Rate limit reset values larger than this are epoch timestamps rather than seconds until
+the reset."""
 
 logger = logging.getLogger(__name__)
 # Regex used to match the `/backends` endpoint, capturing the device name as group(2).
@@ -53,6 +100,40 @@ logger = logging.getLogger(__name__)
 # Capture groups: (/backends/)(<device_name>)(</optional rest of the url>)
 RE_BACKENDS_ENDPOINT = re.compile(r"^(.*/backends/)([^/}]{2,})(.*)$", re.IGNORECASE)
 
+RequestHook = Callable[[str, str, Dict[str, str]], None]
+"""Hook called before a request is sent, with the method, URL and (mutable) headers."""
+
+ResponseHook = Callable[[Response], None]
+"""Hook called with the response of every request, before its status is checked."""
+
+Resolver = Callable[[str], Sequence[str]]
+"""Callable returning the IP addresses of a host name, in order of preference."""
+
+IP_VERSIONS = {"ipv4": socket.AF_INET, "ipv6": socket.AF_INET6}
+
+
+class _InFlightRequest:
+    """A ``GET`` request being sent, whose outcome is shared with identical requests."""
+
+    def __init__(self) -> None:
+        self.done = threading.Event()
+        self.response: Optional[Response] = None
+        self.error: Optional[Exception] = None
+
+    def result(self) -> Response:
+        """Wait for the request to complete and return its response.
+
+        Returns:
+            Response of the request.
+
+        Raises:
+            Exception: The exception raised by the request, if any.
+        """
+        self.done.wait()
+        if self.error is not None:
+            raise self.error
+        return self.response
+
 
 def _get_client_header() -> str:
     """Return the client version."""
@@ -149,6 +230,173 @@ class PostForcelistRetry(Retry):
         return super().is_retry(method, status_code, has_retry_after)
 
 
+def proxy_env_enabled() -> bool:
+    """Return whether proxies can be read from the environment.
+
+    Like other HTTP tools, the ``HTTPS_PROXY``, ``HTTP_PROXY``, ``ALL_PROXY`` and
+    ``NO_PROXY`` environment variables are honored for the hosts without an explicitly
+    configured proxy. Set the ``QISKIT_IBM_RUNTIME_IGNORE_PROXY_ENV`` environment variable
+    to ``True`` to opt out.
+    """
+    return os.getenv(PROXY_ENV_OPT_OUT_ENV_VAR, "False") != "True"
+
+
+def _resolve_addresses(
+    host: str, port: int, ip_version: Optional[str], resolver: Optional[Resolver]
+) -> List[str]:
+    """Return the IP addresses to connect to for a host, in the order to try them.
+
+    Args:
+        host: Host name.
+        port: Port number.
+        ip_version: Preferred IP version, ``ipv4`` or ``ipv6``. Addresses of the
+            preferred version are tried first, and the other addresses after them.
+        resolver: Custom resolver. If ``None``, the system resolver is used.
+
+    Returns:
+        The IP addresses of the host.
+    """
+    if resolver is not None:
+        addresses = list(resolver(host))
+    else:
+        addresses = []
+        for *_, sockaddr in socket.getaddrinfo(host, port, type=socket.SOCK_STREAM):
+            if sockaddr[0] not in addresses:
+                addresses.append(sockaddr[0])
+    if ip_version is None:
+        return addresses
+    preferred = 4 if ip_version == "ipv4" else 6
+    return sorted(addresses, key=lambda address: ipaddress.ip_address(address).version != preferred)
+
+
+# Time spent connecting by the requests of the current thread, per phase.
+_connection_timings = threading.local()
+
+
+def _add_connection_timing(phase: str, duration: float) -> None:
+    """Add the duration of a connection phase to the timings of the current request."""
+    timings = getattr(_connection_timings, "phases", None)
+    if timings is not None:
+        timings[phase] = timings.get(phase, 0.0) + duration
+
+
+class _TimedConnectionMixin:
+    """Connection that records the time taken to connect and to perform the TLS handshake.
+
+    Name resolution is included in the ``connect`` phase.
+    """
+
+    def _new_conn(self) -> socket.socket:
+        """Open the socket, recording the time taken in the ``connect`` phase."""
+        start = time.monotonic()
+        try:
+            return super()._new_conn()  # type: ignore[misc]
+        finally:
+            self._connect_duration = time.monotonic() - start
+            _add_connection_timing("connect", self._connect_duration)
+
+    def connect(self) -> None:
+        """Connect, recording the time taken by the TLS handshake in the ``tls`` phase."""
+        self._connect_duration = 0.0
+        start = time.monotonic()
+        super().connect()  # type: ignore[misc]
+        if isinstance(self, HTTPSConnection):
+            _add_connection_timing("tls", time.monotonic() - start - self._connect_duration)
+
+
+class _ResolvingConnectionMixin:
+    """Connection that resolves the host name with a custom resolver or IP version preference.
+
+    Only the address connected to is changed. The host name is still used for the
+    ``Host`` header and to verify the TLS certificate.
+    """
+
+    ip_version: Optional[str] = None
+    resolver: Optional[Resolver] = None
+
+    def _new_conn(self) -> socket.socket:
+        """Connect to the addresses of the host in order, until one succeeds."""
+        try:
+            addresses = _resolve_addresses(
+                self.host, self.port, self.ip_version, self.resolver  # type: ignore[attr-defined]
+            )
+        except (OSError, ValueError) as ex:
+            logger.debug("Unable to resolve %s: %s", self.host, ex)  # type: ignore[attr-defined]
+            addresses = []
+        if not addresses:
+            # Let the default connection report the resolution error.
+            return super()._new_conn()  # type: ignore[misc]
+
+        host = self._dns_host  # type: ignore[has-type]
+        try:
+            for address in addresses[:-1]:
+                self._dns_host = address
+                try:
+                    return super()._new_conn()  # type: ignore[misc]
+                except ConnectTimeoutError:
+                    logger.debug("Unable to connect to %s, trying the next address.", address)
+            self._dns_host = addresses[-1]
+            return super()._new_conn()  # type: ignore[misc]
+        finally:
+            self._dns_host = host
+
+
+class _TimedHTTPAdapter(HTTPAdapter):
+    """HTTP adapter whose direct connections record their connection timings."""
+
+    _connection_mixins: Tuple[Type, ...] = (_TimedConnectionMixin,)
+
+    def init_poolmanager(self, *args: Any, **kwargs: Any) -> None:
+        super().init_poolmanager(*args, **kwargs)
+        self.poolmanager.pool_classes_by_scheme = {
+            "http": self._pool_class(HTTPConnectionPool, HTTPConnection),
+            "https": self._pool_class(HTTPSConnectionPool, HTTPSConnection),
+        }
+
+    def _pool_class(self, pool_class: Type, connection_class: Type) -> Type:
+        """Return a connection pool class whose connections use the adapter mixins."""
+        connection = type(
+            connection_class.__name__,
+            self._connection_mixins + (connection_class,),
+            self._connection_attributes(),
+        )
+        return type(pool_class.__name__, (pool_class,), {"ConnectionCls": connection})
+
+    def _connection_attributes(self) -> Dict[str, Any]:
+        """Return the class attributes of the connections."""
+        return {}
+
+
+class _ResolvingHTTPAdapter(_TimedHTTPAdapter):
+    """HTTP adapter that connects using a custom resolver or IP version preference."""
+
+    __attrs__ = HTTPAdapter.__attrs__ + ["ip_version", "resolver"]
+    _connection_mixins = (_TimedConnectionMixin, _ResolvingConnectionMixin)
+
+    def __init__(
+        self, ip_version: Optional[str] = None, resolver: Optional[Resolver] = None, **kwargs: Any
+    ) -> None:
+        self.ip_version = ip_version
+        self.resolver = resolver
+        super().__init__(**kwargs)
+
+    def _connection_attributes(self) -> Dict[str, Any]:
+        return {"ip_version": self.ip_version, "resolver": staticmethod(self.resolver)}
+
+
+def _is_json(response: Response) -> bool:
+    """Return whether a response has a JSON content type, or no content type."""
+    content_type = response.headers.get("Content-Type", "")
+    return not content_type or "json" in content_type
+
+
+def _unexpected_content(response: Response) -> str:
+    """Describe a response that is not JSON, with the beginning of its body."""
+    content_type = response.headers.get("Content-Type")
+    snippet = response.text[:CONTENT_SNIPPET_LENGTH]
+    return f"Unexpected {content_type} response (status {response.status_code}): {snippet}"
+
+
 class RetrySession(Session):
     """Custom session with retry and handling of specific parameters.
 
@@ -166,6 +414,12 @@ class RetrySession(Session):
         proxies: Optional[Dict[str, str]] = None,
         auth: Optional[AuthBase] = None,
         timeout: Tuple[float, Union[float, None]] = (5.0, None),
+        request_hooks: Optional[List[RequestHook]] = None,
+        response_hooks: Optional[List[ResponseHook]] = None,
+        ip_version: Optional[str] = None,
+        resolver: Optional[Resolver] = None,
+        exposed_headers: Optional[Sequence[str]] = None,
+        compression_threshold: Optional[int] = None,
     ) -> None:
         """RetrySession constructor.
 
@@ -179,14 +433,56 @@ class RetrySession(Session):
             auth: Authentication handler.
             timeout: Timeout for the requests, in the form of (connection_timeout,
                 total_timeout).
+            request_hooks: Callables invoked before every request is sent. Each hook
+                receives the method, the final URL and the request headers, which it
+                can modify in place.
+            response_hooks: Callables invoked with every response received.
+            ip_version: Preferred IP version to connect with, ``ipv4`` or ``ipv6``.
+                Addresses of the other version are only tried if none of the
+                preferred addresses can be connected to.
+            resolver: Callable returning the IP addresses of a host name, used instead
+                of the system resolver.
+            exposed_headers: Names of the response headers kept in
+                ``last_response_headers``. Defaults to ``EXPOSED_RESPONSE_HEADERS``, the
+                rate limit, request ID and API version headers.
+            compression_threshold: Size, in bytes, above which the bodies of the requests
+                are compressed with gzip, except those mostly made of already compressed
+                payloads. Only set it if the server accepts gzip-encoded request bodies.
+                By default, they are not compressed.
+
+        Raises:
+            ValueError: If ``ip_version`` or ``compression_threshold`` is not valid.
         """
         super().__init__()
+        if ip_version is not None and ip_version not in IP_VERSIONS:
+            raise ValueError(
+                f"Invalid IP version {ip_version}. Valid values are {list(IP_VERSIONS)}."
+            )
+        if compression_threshold is not None and compression_threshold < 0:
+            raise ValueError("The compression threshold must not be negative.")
 
         self.base_url = base_url
         self.custom_header: Optional[str] = None
-        self._initialize_retry(retries_total, retries_connect, backoff_factor)
+        self.request_hooks: List[RequestHook] = list(request_hooks or [])
+        self.response_hooks: List[ResponseHook] = list(response_hooks or [])
+        self._initialize_retry(retries_total, retries_connect, backoff_factor, ip_version, resolver)
         self._initialize_session_parameters(verify, proxies or {}, auth)
         self._timeout = timeout
+        self._in_flight: Dict[Tuple[str, str], _InFlightRequest] = {}
+        self._in_flight_lock = threading.Lock()
+        self.recent_requests: Deque[Dict[str, Any]] = deque(maxlen=REQUEST_HISTORY_SIZE)
+        self.last_error: Optional[Dict[str, Any]] = None
+        self.metrics = RequestMetrics()
+        self.clock_skew: Optional[float] = None
+        """Seconds the server clock is ahead of the local clock, from the last ``Date`` header."""
+        self.exposed_headers = tuple(
+            name.lower() for name in (exposed_headers or EXPOSED_RESPONSE_HEADERS)
+        )
+        self.last_response_headers: Dict[str, str] = {}
+        """Exposed headers of the last response received, keyed by lowercase name."""
+        self._rate_limit: Optional[Tuple[int, float]] = None
+        self._rate_limit_lock = threading.Lock()
+        self.compression_threshold = compression_threshold
 
     def __del__(self) -> None:
         """RetrySession destructor. Closes the session."""
@@ -197,7 +493,12 @@ class RetrySession(Session):
             pass
 
     def _initialize_retry(
-        self, retries_total: int, retries_connect: int, backoff_factor: float
+        self,
+        retries_total: int,
+        retries_connect: int,
+        backoff_factor: float,
+        ip_version: Optional[str] = None,
+        resolver: Optional[Resolver] = None,
     ) -> None:
         """Set the session retry policy.
 
@@ -205,6 +506,8 @@ class RetrySession(Session):
             retries_total: Number of total retries for the requests.
             retries_connect: Number of connect retries for the requests.
             backoff_factor: Backoff factor between retry attempts.
+            ip_version: Preferred IP version to connect with.
+            resolver: Callable returning the IP addresses of a host name.
         """
         retry = PostForcelistRetry(
             total=retries_total,
@@ -213,7 +516,12 @@ class RetrySession(Session):
             status_forcelist=STATUS_FORCELIST,
         )
 
-        retry_adapter = HTTPAdapter(max_retries=retry)
+        if ip_version or resolver:
+            retry_adapter: HTTPAdapter = _ResolvingHTTPAdapter(
+                ip_version=ip_version, resolver=resolver, max_retries=retry
+            )
+        else:
+            retry_adapter = _TimedHTTPAdapter(max_retries=retry)
         self.mount("http://", retry_adapter)
         self.mount("https://", retry_adapter)
 
@@ -242,6 +550,9 @@ class RetrySession(Session):
         If `bare` is not specified, prepend the base URL to the input `url`.
         Timeout value is passed if proxies are not used.
 
+        Identical ``GET`` requests issued concurrently from several threads are sent
+        only once, and all the callers receive the same response.
+
         Args:
             method: Method for the new request (e.g. ``POST``).
             url: URL for the new request.
@@ -255,6 +566,7 @@ class RetrySession(Session):
         Raises:
             RequestsApiError: If the request failed.
             IBMNotAuthorizedError: If the auth token is invalid.
+            ForbiddenApiError: If the account is not authorized to perform the request.
         """
         # pylint: disable=arguments-differ
         if bare:
@@ -323,15 +635,136 @@ class RetrySession(Session):
         self.headers = headers
         self._set_custom_header()
 
+        # Give user hooks the chance to add or modify headers of this request only.
+        headers = headers.copy()
+        for request_hook in self.request_hooks:
+            request_hook(method, final_url, headers)
+
+        if method.upper() != "GET" or kwargs.get("stream"):
+            self._compress_body(final_url, headers, kwargs)
+            return self._send_request(method, final_url, headers, **kwargs)
+
+        # Coalesce identical concurrent GET requests (for example, several threads
+        # instantiating the same backend) into a single request to the server.
+        key = (
+            final_url,
+            repr((sorted((kwargs.get("params") or {}).items()), sorted(headers.items()))),
+        )
+        with self._in_flight_lock:
+            in_flight = self._in_flight.get(key)
+            if in_flight is not None:
+                logger.debug("Waiting for in-flight request to %s.", final_url)
+                is_leader = False
+            else:
+                in_flight = self._in_flight[key] = _InFlightRequest()
+                is_leader = True
+        if not is_leader:
+            return in_flight.result()
+
+        try:
+            in_flight.response = self._send_request(method, final_url, headers, **kwargs)
+            return in_flight.response
+        except Exception as ex:
+            in_flight.error = ex
+            raise
+        finally:
+            with self._in_flight_lock:
+                del self._in_flight[key]
+            in_flight.done.set()
+
+    def _compress_body(self, url: str, headers: Dict[str, str], kwargs: Dict[str, Any]) -> None:
+        """Compress the body of a request with gzip, if it is above the compression threshold.
+
+        Bodies mostly made of base64 payloads, like the circuits of job submissions, are
+        already compressed, so they are sent as they are without spending time on gzip.
+        Other bodies that do not compress well are also sent as they are.
+
+        Args:
+            url: Final URL of the request.
+            headers: Headers of the request, updated in place.
+            kwargs: Arguments of the request, updated in place.
+        """
+        if self.compression_threshold is None:
+            return
+        if kwargs.get("json") is not None:
+            body = json.dumps(kwargs["json"], allow_nan=False).encode("utf-8")
+        elif isinstance(kwargs.get("data"), (str, bytes)):
+            data = kwargs["data"]
+            body = data.encode("utf-8") if isinstance(data, str) else data
+        else:
+            return
+        if len(body) <= self.compression_threshold:
+            return
+        path = urlparse(url).path
+        encoded = sum(len(match) for match in ENCODED_PAYLOAD_PATTERN.findall(body))
+        if encoded > ENCODED_PAYLOAD_MAX_FRACTION * len(body):
+            logger.debug(
+                "Sending the %d-byte body of %s uncompressed, it is mostly encoded payloads.",
+                len(body),
+                path,
+            )
+            return
+        compressed = gzip.compress(body)
+        ratio = len(compressed) / len(body)
+        if ratio > COMPRESSION_MAX_RATIO:
+            logger.debug(
+                "Sending the %d-byte body of %s uncompressed, it compresses to %.0f%%.",
+                len(body),
+                path,
+                ratio * 100,
+            )
+            return
+        logger.debug(
+            "Compressed the %d-byte body of %s to %d bytes (%.0f%%).",
+            len(body),
+            path,
+            len(compressed),
+            ratio * 100,
+        )
+        if kwargs.pop("json", None) is not None:
+            headers["Content-Type"] = "application/json"
+        kwargs["data"] = compressed
+        headers["Content-Encoding"] = "gzip"
+
+    def _send_request(
+        self, method: str, url: str, headers: Dict[str, str], **kwargs: Any
+    ) -> Response:
+        """Send a request and wrap any errors in IBM Quantum specific exceptions.
+
+        Args:
+            method: Method for the new request (e.g. ``POST``).
+            url: Final URL for the new request.
+            headers: Headers for the new request.
+            **kwargs: Additional arguments for the request.
+
+        Returns:
+            Response object.
+
+        Raises:
+            RequestsApiError: If the request failed.
+            IBMNotAuthorizedError: If the auth token is invalid.
+            ForbiddenApiError: If the account is not authorized to perform the request.
+            UnexpectedContentTypeError: If the response is an HTML page, or an error
+                response is not JSON.
+        """
+        record = None
+        self._pace_rate_limit()
+        _connection_timings.phases = {}
+        start = time.monotonic()
         try:
-            self._log_request_info(final_url, method, kwargs)
-            response = super().request(method, final_url, headers=headers, **kwargs)
+            self._log_request_info(url, method, kwargs)
+            response = super().request(method, url, headers=headers, **kwargs)
+            record = self._record_request(method, url, response, time.monotonic() - start)
+            for response_hook in self.response_hooks:
+                response_hook(response)
             response.raise_for_status()
         except RequestException as ex:
             # Wrap the requests exceptions into a IBM Q custom one, for
             # compatibility.
             message = str(ex)
             status_code = -1
+            error_code = None
+            error_class = RequestsApiError
             if ex.response is not None:
                 status_code = ex.response.status_code
                 try:
@@ -339,19 +772,210 @@ class RetrySession(Session):
                     message += ". {}, Error code: {}.".format(
                         error_json["message"], error_json["code"]
                     )
+                    error_code = str(error_json["code"])
                     logger.debug(
                         "Response uber-trace-id: %s",
                         ex.response.headers["uber-trace-id"],
                     )
                 except Exception:  # pylint: disable=broad-except
                     # the response did not contain the expected json.
-                    message += f". {ex.response.text}"
+                    if _is_json(ex.response):
+                        message += f". {ex.response.text}"
+                    else:
+                        message += f". {_unexpected_content(ex.response)}"
+                        error_class = UnexpectedContentTypeError
+            if record is None:
+                record = self._record_request(method, url, ex.response, time.monotonic() - start)
+            # Drop query strings, which may contain credentials, from the recorded message.
+            self.last_error = {**record, "message": re.sub(r"\?\S*", "?...", message)}
             if status_code == 401:
-                raise IBMNotAuthorizedError(message) from ex
-            raise RequestsApiError(message, status_code) from ex
+                raise IBMNotAuthorizedError(f"{message} {UNAUTHENTICATED_GUIDANCE}") from ex
+            if status_code == 403:
+                guidance = forbidden_guidance(self._channel(), error_code)
+                raise ForbiddenApiError(f"{message} {guidance}", error_code) from ex
+            raise error_class(message, status_code) from ex
+
+        # Intermediaries such as proxies and gateways can answer with an HTML page, which
+        # would otherwise surface as a confusing JSON decoding error.
+        if response.headers.get("Content-Type", "").startswith("text/html"):
+            message = _unexpected_content(response)
+            self.last_error = {**record, "message": message}
+            raise UnexpectedContentTypeError(message, response.status_code)
 
         return response
 
+    def _channel(self) -> Optional[str]:
+        """Return the channel of the account, as told by its authentication handler."""
+        if isinstance(self.auth, (CloudAuth, TrustedProfileAuth)):
+            return "ibm_cloud"
+        if isinstance(self.auth, QuantumAuth):
+            return "ibm_quantum"
+        return None
+
+    def _record_request(
+        self, method: str, url: str, response: Optional[Response], duration: float
+    ) -> Dict[str, Any]:
+        """Record a request in the history of recent requests and in the metrics.
+
+        Only the path of the URL is kept, so that query parameters such as access
+        tokens are never recorded.
+
+        Args:
+            method: Method of the request.
+            url: URL of the request.
+            response: Response received, or ``None`` if the request failed without one.
+            duration: Time taken by the request, in seconds.
+
+        Returns:
+            The recorded entry.
+        """
+        request_id = None
+        if response is not None:
+            request_id = next(
+                (response.headers[name] for name in REQUEST_ID_HEADERS if name in response.headers),
+                None,
+            )
+        now = datetime.now(timezone.utc)
+        if response is not None:
+            self._update_clock_skew(response, now)
+            self._update_rate_limit(response)
+            self.last_response_headers = {
+                name: response.headers[name]
+                for name in self.exposed_headers
+                if name in response.headers
+            }
+        record = {
+            "time": now.isoformat(),
+            "method": method.upper(),
+            "path": urlparse(url).path,
+            "status_code": response.status_code if response is not None else None,
+            "request_id": request_id,
+        }
+        record["timings"] = self._request_timings(response, duration)
+        logger.debug(
+            "Timings of %s %s: %s",
+            record["method"],
+            record["path"],
+            ", ".join(
+                f"{phase}={value * 1000:.1f}ms"
+                for phase, value in record["timings"].items()
+                if value is not None
+            ),
+        )
+        self.recent_requests.append(record)
+        self.metrics.observe(
+            method,
+            record["path"],
+            response.status_code if response is not None else -1,
+            duration,
+            phases=record["timings"],
+        )
+        return record
+
+    @staticmethod
+    def _request_timings(response: Optional[Response], duration: float) -> Dict[str, Any]:
+        """Return the time taken by each phase of a request.
+
+        Args:
+            response: Response received, or ``None`` if the request failed without one.
+            duration: Time taken by the request, in seconds.
+
+        Returns:
+            The durations, in seconds, of the ``connect`` phase, including name resolution,
+            of the ``tls`` handshake, of the wait for the first byte of the response
+            (``ttfb``), and of the whole request (``total``). The connection phases are
+            ``None`` if an open connection was reused or a proxy was used.
+        """
+        phases = getattr(_connection_timings, "phases", None) or {}
+        connect = phases.get("connect")
+        tls = phases.get("tls")
+        ttfb = None
+        if response is not None and response.elapsed:
+            ttfb = max(response.elapsed.total_seconds() - (connect or 0) - (tls or 0), 0.0)
+        return {"connect": connect, "tls": tls, "ttfb": ttfb, "total": duration}
+
+    def merge_environment_settings(  # type: ignore[no-untyped-def]
+        self, url, proxies, stream, verify, cert
+    ):
+        """Merge the request settings with the environment settings.
+
+        Proxies from the environment are left out if opted out of, see :func:`proxy_env_enabled`.
+        """
+        # The parent method adds the environment proxies to ``proxies`` in place.
+        request_proxies = dict(proxies or {})
+        settings = super().merge_environment_settings(url, proxies, stream, verify, cert)
+        if not proxy_env_enabled():
+            settings["proxies"] = merge_setting(request_proxies, self.proxies)
+        return settings
+
+    def _update_clock_skew(self, response: Response, now: datetime) -> None:
+        """Update the clock skew from the ``Date`` header of a response.
+
+        Args:
+            response: Response received.
+            now: Local time at which the response was received.
+        """
+        try:
+            server_date = parsedate_to_datetime(response.headers["Date"])
+        except (KeyError, TypeError, ValueError, IndexError):
+            return
+        if server_date.tzinfo is None:
+            server_date = server_date.replace(tzinfo=timezone.utc)
+        self.clock_skew = (server_date - now).total_seconds()
+
+    def _update_rate_limit(self, response: Response) -> None:
+        """Update the rate limit state from the ``X-RateLimit-*`` headers of a response.
+
+        Args:
+            response: Response received.
+        """
+        try:
+            remaining = int(response.headers["X-RateLimit-Remaining"])
+            reset = float(response.headers["X-RateLimit-Reset"])
+        except (KeyError, TypeError, ValueError):
+            return
+        if reset > RATE_LIMIT_EPOCH_RESET:
+            reset -= self.server_time().timestamp()
+        with self._rate_limit_lock:
+            self._rate_limit = (remaining, time.monotonic() + max(reset, 0))
+
+    def _pace_rate_limit(self) -> None:
+        """Delay the next request if the rate limit of the server is nearly exhausted.
+
+        When few requests remain in the current rate limit window, the remaining ones
+        are spread until the window resets, so that bulk operations do not get ``429``
+        responses.
+        """
+        with self._rate_limit_lock:
+            if self._rate_limit is None:
+                return
+            remaining, reset_at = self._rate_limit
+            time_to_reset = reset_at - time.monotonic()
+            if time_to_reset <= 0:
+                self._rate_limit = None
+                return
+            self._rate_limit = (max(remaining - 1, 0), reset_at)
+        if remaining > RATE_LIMIT_PACING_THRESHOLD:
+            return
+        delay = min(time_to_reset / (remaining + 1), RATE_LIMIT_MAX_DELAY)
+        logger.debug(
+            "%s requests remaining in the rate limit window, waiting %.1f seconds.",
+            remaining,
+            delay,
+        )
+        time.sleep(delay)
+
+    def server_time(self) -> datetime:
+        """Return the current time according to the server clock.
+
+        The local clock is corrected by the skew observed in the ``Date`` header of
+        the last response. The local time is returned if no response was received yet.
+
+        Returns:
+            The estimated server time, in UTC.
+        """
+        return datetime.now(timezone.utc) + timedelta(seconds=self.clock_skew or 0)
+
     def _log_request_info(self, url: str, method: str, request_data: Dict[str, Any]) -> None:
         """Log the request data, filtering out specific information.
 
@@ -439,4 +1063,14 @@ class RetrySession(Session):
         """Overwrite Session's getstate to include all attributes."""
         state = super().__getstate__()  # type: ignore
         state.update(self.__dict__)
+        state.pop("_in_flight", None)
+        state.pop("_in_flight_lock", None)
+        state.pop("_rate_limit_lock", None)
         return state
+
+    def __setstate__(self, state: Dict) -> None:
+        """Overwrite Session's setstate to recreate the in-flight request tracking and locks."""
+        super().__setstate__(state)  # type: ignore
+        self._in_flight = {}
+        self._in_flight_lock = threading.Lock()
+        self._rate_limit_lock = threading.Lock()
@@ -17,6 +17,8 @@ from abc import ABC, abstractmethod
 from typing import Dict, Optional, Any, Union, TypeVar, Generic, Type
 import copy
 import logging
+import threading
+from concurrent import futures
 from dataclasses import asdict, replace
 import warnings
 
@@ -35,10 +37,15 @@ from .options.utils import merge_options, set_default_error_levels, merge_option
 from .runtime_job import RuntimeJob
 from .runtime_job_v2 import RuntimeJobV2
 from .ibm_backend import IBMBackend
-from .utils import validate_isa_circuits, validate_no_dd_with_dynamic_circuits
+from .utils import (
+    validate_isa_circuits,
+    validate_no_dd_with_dynamic_circuits,
+    validate_options_profile,
+)
 from .utils.default_session import get_cm_session
 from .utils.deprecation import issue_deprecation_msg, deprecate_function
 from .utils.utils import is_simulator
+from .exceptions import IBMInputValueError, RuntimeJobFailureError
 from .constants import DEFAULT_DECODERS
 from .qiskit_runtime_service import QiskitRuntimeService
 from .fake_provider.local_service import QiskitRuntimeLocalService
@@ -71,9 +78,13 @@ class BasePrimitiveV2(ABC, Generic[OptionsT]):
                 * A :class:`Session` if you are using session execution mode.
                 * A :class:`Batch` if you are using batch execution mode.
 
+                If not specified, the session of the enclosing context manager is used,
+                or else the default backend of the account of the last created service.
+
             options: Primitive options, see :class:`qiskit_ibm_runtime.options.EstimatorOptions`
                 and :class:`qiskit_ibm_runtime.options.SamplerOptions` for detailed description
-                on estimator and sampler options, respectively.
+                on estimator and sampler options, respectively. If not specified, the
+                default options of the account are used.
 
         Raises:
             ValueError: Invalid arguments are given.
@@ -109,9 +120,22 @@ class BasePrimitiveV2(ABC, Generic[OptionsT]):
             self._backend = self._service.backend(  # type: ignore
                 name=self._mode.backend(), instance=self._mode._instance
             )
+        elif (
+            QiskitRuntimeService.global_service is not None
+            and QiskitRuntimeService.global_service.default_backend
+        ):
+            self._service = QiskitRuntimeService.global_service
+            self._backend = self._service.backend(self._service.default_backend)
         else:
             raise ValueError("A backend or session must be specified.")
 
+        if options is None and isinstance(self._service, QiskitRuntimeService):
+            default_options = self._service.default_options.get(self._program_id())
+            if default_options:
+                if self._backend:
+                    validate_options_profile(default_options, self._backend)
+                self._options.update(**default_options)
+
     def _run(self, pubs: Union[list[EstimatorPub], list[SamplerPub]]) -> RuntimeJobV2:
         """Run the primitive.
 
@@ -169,6 +193,71 @@ class BasePrimitiveV2(ABC, Generic[OptionsT]):
             inputs=primitive_inputs,
         )
 
+    def run_after(self, job: RuntimeJobV2, *args: Any, **kwargs: Any) -> futures.Future:
+        """Submit a request to the primitive once another job has completed successfully.
+
+        This chains jobs on the client side, for example to run an experiment only after
+        a calibration job is done. The arguments are those of the ``run()`` method of the
+        primitive, and are passed to it when ``job`` is done. Each call waits for ``job`` in
+        a thread of its own, so chains waiting for long jobs do not delay one another.
+
+        Example::
+
+            calibration = sampler.run([calibration_circuit])
+            experiment = sampler.run_after(calibration, [experiment_circuit])
+            result = experiment.result().result()
+
+        Args:
+            job: Job to wait for.
+            *args: Positional arguments of ``run()``.
+            **kwargs: Keyword arguments of ``run()``.
+
+        Returns:
+            A future whose result is the submitted job. If ``job`` is cancelled or fails,
+            nothing is submitted and the future raises a :class:`RuntimeJobFailureError`.
+
+        Raises:
+            IBMInputValueError: If ``job`` is not a job submitted to Qiskit Runtime, such as
+                the job of a primitive run in local mode.
+        """
+        if not isinstance(job, RuntimeJobV2):
+            raise IBMInputValueError(
+                f"run_after() can only wait for a RuntimeJobV2, got {type(job).__name__}, "
+                "which cannot be waited for with wait_for_final_state()."
+            )
+        future: futures.Future = futures.Future()
+        future.set_running_or_notify_cancel()
+        threading.Thread(
+            target=self._submit_after,
+            args=(future, job, args, kwargs),
+            name=f"run_after-{job.job_id()}",
+            daemon=True,
+        ).start()
+        return future
+
+    def _submit_after(
+        self, future: futures.Future, job: RuntimeJobV2, args: tuple, kwargs: dict
+    ) -> None:
+        """Wait for a job to complete, then submit a request to the primitive.
+
+        The submitted job, or the exception raised, is set as the result of ``future``.
+        """
+        try:
+            future.set_result(self._wait_and_run(job, args, kwargs))
+        except Exception as ex:  # pylint: disable=broad-except
+            future.set_exception(ex)
+
+    def _wait_and_run(self, job: RuntimeJobV2, args: tuple, kwargs: dict) -> RuntimeJobV2:
+        """Wait for a job to complete, then submit a request to the primitive."""
+        status = job.wait_for_final_state()
+        if status != "DONE":
+            raise RuntimeJobFailureError(
+                f"Job {job.job_id()} ended with status {status}, "
+                "so the dependent job was not submitted."
+            )
+        logger.debug("Job %s is done, submitting the dependent job.", job.job_id())
+        return self.run(*args, **kwargs)  # type: ignore[attr-defined]
+
     @property
     def session(self) -> Optional[Session]:
         """Return session used by this primitive.
@@ -193,6 +282,33 @@ class BasePrimitiveV2(ABC, Generic[OptionsT]):
         """Return options"""
         return self._options
 
+    def apply_profile(self, name: str) -> None:
+        """Update the options with a named profile saved with the account.
+
+        Profiles are saved with :meth:`.QiskitRuntimeService.save_account`, for example::
+
+            QiskitRuntimeService.save_account(
+                token=token,
+                option_profiles={"fast": {"default_shots": 1000}},
+                overwrite=True,
+            )
+            sampler = SamplerV2(mode=backend)
+            sampler.apply_profile("fast")
+
+        Args:
+            name: Name of the profile.
+
+        Raises:
+            IBMInputValueError: If the account does not have a profile with this name,
+                or the profile is not supported by the backend.
+        """
+        if not isinstance(self._service, QiskitRuntimeService):
+            raise IBMInputValueError("Option profiles are only available with an account.")
+        profile = self._service.option_profile(name)
+        if self._backend:
+            validate_options_profile(profile, self._backend)
+        self._options.update(**profile)
+
     def _set_options(self, options: Optional[Union[Dict, OptionsT]] = None) -> None:
         """Set options."""
         if options is None:
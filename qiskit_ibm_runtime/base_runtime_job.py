@@ -16,6 +16,7 @@ from abc import ABC, abstractmethod
 from typing import Any, Optional, Callable, Dict, Type, Union, Sequence, List, Tuple
 import json
 import logging
+import time
 from concurrent import futures
 import traceback
 import queue
@@ -38,6 +39,7 @@ from .exceptions import (
     IBMError,
     IBMApiError,
     IBMRuntimeError,
+    RuntimeJobTimeoutError,
 )
 from .utils.result_decoder import ResultDecoder
 from .api.clients import RuntimeClient, RuntimeWebsocketClient, WebsocketClientCloseCode
@@ -46,6 +48,9 @@ from .api.client_parameters import ClientParameters
 
 logger = logging.getLogger(__name__)
 
+_NOT_FETCHED = object()
+"""Marks a job attribute that was not retrieved from the server yet."""
+
 
 class BaseRuntimeJob(ABC):
     """Base Runtime Job class."""
@@ -55,6 +60,9 @@ class BaseRuntimeJob(ABC):
 
     _executor = futures.ThreadPoolExecutor(thread_name_prefix="runtime_job")
 
+    _MAX_POLL_INTERVAL = 5.0
+    """Longest time, in seconds, between two status polls while waiting for a job."""
+
     JOB_FINAL_STATES: Tuple[Any, ...] = ()
     ERROR: Union[str, RuntimeJobStatus] = None
 
@@ -107,7 +115,10 @@ class BaseRuntimeJob(ABC):
         self._service = service
         self._session_id = session_id
         self._tags = tags
+        self._owner: Union[Optional[str], object] = _NOT_FETCHED
+        self._results_expiration: Union[Optional[str], object] = _NOT_FETCHED
         self._usage_estimation: Dict[str, Any] = {}
+        self._submission_stats: Optional[Dict[str, Any]] = None
         self._version = version
         self._queue_info: QueueInfo = None
         self._user_callback = user_callback
@@ -307,6 +318,43 @@ class BaseRuntimeJob(ABC):
 
         return True
 
+    def _poll_for_final_state(
+        self, start_time: float, timeout: Optional[float], poll_interval: float
+    ) -> Any:
+        """Poll the status of the job until it is final.
+
+        The time between two polls starts at ``poll_interval`` and doubles after each
+        poll, up to ``_MAX_POLL_INTERVAL``, so that long-running jobs are not polled
+        needlessly often. A ``poll_interval`` longer than ``_MAX_POLL_INTERVAL`` is kept
+        as is: the status is never polled more often than requested.
+
+        Args:
+            start_time: Time at which the wait started, as returned by ``time.monotonic()``.
+            timeout: Seconds to wait for the job. If ``None``, wait indefinitely.
+            poll_interval: Seconds to wait before the second poll.
+
+        Returns:
+            The final status of the job.
+
+        Raises:
+            RuntimeJobTimeoutError: If the job does not complete within given timeout.
+        """
+        status = self.status()
+        interval = poll_interval
+        while status not in self.JOB_FINAL_STATES:
+            # The monotonic clock is not affected by system clock changes, and the elapsed
+            # time includes the time spent retrying status requests.
+            elapsed_time = time.monotonic() - start_time
+            if timeout is not None and elapsed_time >= timeout:
+                raise RuntimeJobTimeoutError(
+                    f"Timed out waiting for job to complete after {timeout} secs. "
+                    f"Last status: {status}."
+                )
+            time.sleep(interval if timeout is None else min(interval, timeout - elapsed_time))
+            interval = min(interval * 2, max(poll_interval, self._MAX_POLL_INTERVAL))
+            status = self.status()
+        return status
+
     def _start_websocket_client(self) -> None:
         """Start websocket client to stream results."""
         try:
@@ -442,6 +490,34 @@ class BaseRuntimeJob(ABC):
             self._session_id = response.get("session_id", None)
         return self._session_id
 
+    @property
+    def owner(self) -> Optional[str]:
+        """ID of the user who submitted the job.
+
+        Returns:
+            User ID of the job owner, or ``None`` if not reported by the server.
+        """
+        if self._owner is _NOT_FETCHED:
+            response = self._api_client.job_get(job_id=self.job_id())
+            self._owner = response.get("user_id", None)
+        return self._owner  # type: ignore[return-value]
+
+    @property
+    def results_expiration(self) -> Optional[datetime]:
+        """Date after which the job results are removed from the server.
+
+        Returns:
+            The expiration date of the job results in local time, or ``None`` if not
+            reported by the server.
+        """
+        if self._results_expiration is _NOT_FETCHED:
+            response = self._api_client.job_get(job_id=self.job_id())
+            self._results_expiration = response.get("results_expiration", None)
+
+        if not self._results_expiration:
+            return None
+        return utc_to_local(self._results_expiration)
+
     @property
     def tags(self) -> List:
         """Job tags.
@@ -468,6 +544,23 @@ class BaseRuntimeJob(ABC):
 
         return self._usage_estimation
 
+    @property
+    def submission_stats(self) -> Optional[Dict[str, Any]]:
+        """Return statistics of the payload sent when this job was submitted.
+
+        Returns:
+            ``None`` if the job was not submitted by this client, otherwise a dictionary with:
+
+            * ``num_pubs``: Number of PUBs.
+            * ``num_circuits``: Number of circuits encoded.
+            * ``circuit_bytes``: Size of the encoded circuits before compression, in bytes.
+            * ``encoded_circuit_bytes``: Size of the encoded circuits after compression,
+              in bytes.
+            * ``payload_bytes``: Size of the request payload, in bytes.
+            * ``encoding_time``: Time spent encoding the payload, in seconds.
+        """
+        return self._submission_stats
+
     @property
     def instance(self) -> Optional[str]:
         """For ibm_quantum channel jobs, return the instance where the job was run.
@@ -73,6 +73,8 @@ class Batch(Session):
     <https://docs.quantum.ibm.com/run/run-jobs-batch>`_" tutorial.
     """
 
+    _mode = "batch"
+
     def __init__(
         self,
         service: Optional[QiskitRuntimeService] = None,
@@ -99,12 +101,3 @@ class Batch(Session):
             ValueError: If an input value is invalid.
         """
         super().__init__(service=service, backend=backend, max_time=max_time)
-
-    def _create_session(self) -> Optional[str]:
-        """Create a session."""
-        if isinstance(self._service, QiskitRuntimeService):
-            session = self._service._api_client.create_session(
-                self.backend(), self._instance, self._max_time, self._service.channel, "batch"
-            )
-            return session.get("id")
-        return None
@@ -36,6 +36,7 @@ from .utils.deprecation import deprecate_arguments, issue_deprecation_msg
 from .utils.qctrl import validate as qctrl_validate
 from .utils.qctrl import validate_v2 as qctrl_validate_v2
 from .utils import validate_estimator_pubs
+from .utils.openqasm3 import coerce_qasm3_pubs
 
 # pylint: disable=unused-import,cyclic-import
 from .session import Session
@@ -175,6 +176,8 @@ class EstimatorV2(BasePrimitiveV2[EstimatorOptions], Estimator, BaseEstimatorV2)
         Args:
             pubs: An iterable of pub-like (primitive unified bloc) objects, such as
                 tuples ``(circuit, observables)`` or ``(circuit, observables, parameter_values)``.
+                Circuits can also be given as OpenQASM 3 program strings, which requires
+                the ``qiskit-qasm3-import`` package.
             precision: The target precision for expectation value estimates of each
                 run Estimator Pub that does not specify its own precision. If None
                 the estimator's default precision value will be used.
@@ -183,7 +186,7 @@ class EstimatorV2(BasePrimitiveV2[EstimatorOptions], Estimator, BaseEstimatorV2)
             Submitted job.
 
         """
-        coerced_pubs = [EstimatorPub.coerce(pub, precision) for pub in pubs]
+        coerced_pubs = [EstimatorPub.coerce(pub, precision) for pub in coerce_qasm3_pubs(pubs)]
         validate_estimator_pubs(coerced_pubs)
         return self._run(coerced_pubs)  # type: ignore[arg-type]
 
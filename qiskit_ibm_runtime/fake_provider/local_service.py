@@ -40,6 +40,21 @@ from ..runtime_options import RuntimeOptions
 
 logger = logging.getLogger(__name__)
 
+LOCAL_SIMULATOR_NAMES = ("aer_simulator",)
+"""Names of the simulators available in local testing mode, besides the fake backends."""
+
+
+def is_local_backend_name(name: str) -> bool:
+    """Return whether a backend name refers to a backend of local testing mode.
+
+    Args:
+        name: Backend name.
+
+    Returns:
+        Whether the name is the name of a fake backend or of a local simulator.
+    """
+    return name in LOCAL_SIMULATOR_NAMES or name.startswith("fake_")
+
 
 class QiskitRuntimeLocalService:
     """Class for local testing mode."""
@@ -54,15 +69,27 @@ class QiskitRuntimeLocalService:
         """
         self._channel_strategy = None
 
-    def backend(self, name: str = None) -> FakeBackendV2:
+    def backend(self, name: str = None) -> BackendV2:
         """Return a single fake backend matching the specified filters.
 
         Args:
-            name: The name of the backend.
+            name: The name of the backend. ``aer_simulator`` returns an ideal
+                ``AerSimulator``.
 
         Returns:
             Backend: A backend matching the filtering.
+
+        Raises:
+            MissingOptionalLibraryError: If ``aer_simulator`` is requested and ``qiskit-aer``
+                is not installed.
+            QiskitBackendNotFoundError: If no fake backend has this name.
         """
+        if name in LOCAL_SIMULATOR_NAMES:
+            optionals.HAS_AER.require_now("the aer_simulator backend")
+            # pylint: disable=import-outside-toplevel
+            from qiskit_aer import AerSimulator
+
+            return AerSimulator()
         return self.backends(name=name)[0]
 
     def backends(
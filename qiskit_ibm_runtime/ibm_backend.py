@@ -358,6 +358,10 @@ class IBMBackend(Backend):
         gates properties (such as gate length and error), and other general
         properties of the backend.
 
+        Each property entry keeps the raw ``value`` and ``unit`` reported by the
+        server, while accessors such as ``t1()``, ``t2()`` and ``gate_length()``
+        return durations normalized to seconds.
+
         The schema for backend properties can be found in
         `Qiskit/ibm-quantum-schemas/backend_properties
         <https://github.com/Qiskit/ibm-quantum-schemas/blob/main/schemas/backend_properties_schema.json>`_.
@@ -392,7 +396,9 @@ class IBMBackend(Backend):
                 raise TypeError("'{}' is not of type 'datetime'.")
             datetime = local_to_utc(datetime)
         if datetime or refresh or self._properties is None:
-            api_properties = self._api_client.backend_properties(self.name, datetime=datetime)
+            api_properties = self._api_client.backend_properties(
+                self.name, datetime=datetime, refresh=refresh
+            )
             if not api_properties:
                 return None
             backend_properties = properties_from_server_data(api_properties)
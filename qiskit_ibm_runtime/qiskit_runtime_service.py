@@ -12,27 +12,39 @@
 
 """Qiskit runtime service."""
 
+import copy
+import importlib.metadata
 import json
 import logging
+import os
+import platform
 import traceback
 import warnings
-from datetime import datetime
+from datetime import datetime, timedelta, timezone
 from collections import OrderedDict
-from typing import Dict, Callable, Optional, Union, List, Any, Type, Sequence
+from concurrent.futures import ThreadPoolExecutor
+from typing import Dict, Callable, Optional, Union, List, Any, Type, Sequence, Tuple, Iterator
 
 from qiskit.providers.backend import BackendV2 as Backend
 from qiskit.providers.exceptions import QiskitBackendNotFoundError
+from qiskit.providers.models import BackendStatus
 from qiskit.providers.providerutils import filter_backends
 
 from qiskit_ibm_runtime import ibm_backend
 from .proxies import ProxyConfiguration
 from .utils.deprecation import issue_deprecation_msg, deprecate_function
-from .utils.hgp import to_instance_format, from_instance_format
-from .utils.backend_decoder import configuration_from_server_data
+from .utils.hgp import InstanceGroup, to_instance_format, from_instance_format, in_instance_group
+from .utils.backend_decoder import configuration_from_server_data, properties_from_server_data
+from .utils.benchmarks import BackendBenchmarks, fleet_benchmarks
+from .utils.fleet_events import FleetMonitor
+from .utils.paged_list import PagedList
+from .utils.usage_forecast import SECONDS_PER_DAY, UsageForecast, forecast_usage
 
 from .accounts import AccountManager, Account, ChannelType
 from .api.clients import AuthClient, VersionClient
 from .api.clients.runtime import RuntimeClient
+from .api.session import RequestHook, ResponseHook, Resolver
+from .api.cache_storage import CacheStorage
 from .api.exceptions import RequestsApiError
 from .constants import QISKIT_IBM_RUNTIME_API_URL
 from .exceptions import IBMNotAuthorizedError, IBMInputValueError, IBMAccountError
@@ -42,14 +54,24 @@ from .utils.result_decoder import ResultDecoder
 from .runtime_job import RuntimeJob
 from .runtime_job_v2 import RuntimeJobV2
 from .utils import RuntimeDecoder, RuntimeEncoder, validate_job_tags
+from .utils.converters import str_to_utc
 from .api.client_parameters import ClientParameters
 from .runtime_options import RuntimeOptions
 from .ibm_backend import IBMBackend
+from .version import __version__
 
 logger = logging.getLogger(__name__)
 
 SERVICE_NAME = "runtime"
 
+RESULTS_EXPIRY_WARNING_DAYS = 7
+DELETE_JOBS_MAX_WORKERS = 10
+USAGE_PAGE_SIZE = 100
+JOBS_WINDOW_DAYS = 30
+JOBS_WINDOW_MAX_WORKERS = 4
+FLEET_SNAPSHOT_MAX_WORKERS = 8
+BACKEND_CONFIGURATIONS_MAX_WORKERS = 8
+
 
 class QiskitRuntimeService:
     """Class for interacting with the Qiskit Runtime service."""
@@ -68,6 +90,14 @@ class QiskitRuntimeService:
         verify: Optional[bool] = None,
         channel_strategy: Optional[str] = None,
         private_endpoint: Optional[bool] = None,
+        request_hooks: Optional[List[RequestHook]] = None,
+        response_hooks: Optional[List[ResponseHook]] = None,
+        ip_version: Optional[str] = None,
+        resolver: Optional[Resolver] = None,
+        exposed_headers: Optional[List[str]] = None,
+        cache_storage: Optional[CacheStorage] = None,
+        trusted_profile: Optional[str] = None,
+        compression_threshold: Optional[int] = None,
     ) -> None:
         """QiskitRuntimeService constructor
 
@@ -104,6 +134,35 @@ class QiskitRuntimeService:
             verify: Whether to verify the server's TLS certificate.
             channel_strategy: Error mitigation strategy.
             private_endpoint: Connect to private API URL.
+            request_hooks: Callables invoked before every request made to the API, for
+                example to add custom headers. Each hook receives the HTTP method, the
+                request URL and the request headers, which it can modify in place.
+            response_hooks: Callables invoked with the ``requests.Response`` of every
+                request made to the API, for example for audit logging.
+            ip_version: Preferred IP version to connect to the API with, ``ipv4`` or ``ipv6``.
+                Addresses of the other version are only tried if none of the preferred
+                addresses can be connected to. Useful on networks with a broken IPv6 path.
+            resolver: Callable returning the IP addresses of a host name, used instead of
+                the system resolver to connect to the API. It does not apply to connections
+                made through a proxy.
+            exposed_headers: Names of the response headers returned by
+                :meth:`last_response_headers`. Defaults to the rate limit, request ID and
+                API version headers.
+            cache_storage: Storage of the cached backend configurations and properties,
+                such as a :class:`~qiskit_ibm_runtime.api.cache_storage.DiskCacheStorage`
+                or :class:`~qiskit_ibm_runtime.api.cache_storage.RedisCacheStorage`, so that
+                several processes can share them. By default, backend configurations are
+                cached in memory and backend properties are not cached.
+            trusted_profile: ID or name of an IBM Cloud trusted profile to authenticate with,
+                instead of an API key, from code running in IBM Cloud with a compute resource
+                identity, such as a Kubernetes pod or a Code Engine job. The compute resource
+                token of the workload is exchanged for short-lived IAM access tokens of the
+                trusted profile. Only supported on the ``ibm_cloud`` channel.
+            compression_threshold: Size, in bytes, above which the bodies of the requests
+                are compressed with gzip to reduce the upload time. Bodies mostly made of
+                encoded circuits, which are already compressed, and bodies that do not
+                compress well are sent uncompressed. Only set it if the server accepts
+                gzip-encoded request bodies. By default, request bodies are not compressed.
 
         Returns:
             An instance of QiskitRuntimeService.
@@ -123,6 +182,7 @@ class QiskitRuntimeService:
             proxies=ProxyConfiguration(**proxies) if proxies else None,
             verify=verify,
             channel_strategy=channel_strategy,
+            trusted_profile=trusted_profile,
         )
 
         if private_endpoint is not None:
@@ -136,11 +196,20 @@ class QiskitRuntimeService:
             proxies=self._account.proxies,
             verify=self._account.verify,
             private_endpoint=self._account.private_endpoint,
+            request_hooks=request_hooks,
+            response_hooks=response_hooks,
+            ip_version=ip_version,
+            resolver=resolver,
+            exposed_headers=exposed_headers,
+            cache_storage=cache_storage,
+            trusted_profile=self._account.trusted_profile,
+            compression_threshold=compression_threshold,
         )
 
         self._channel_strategy = channel_strategy or self._account.channel_strategy
         self._channel = self._account.channel
         self._backend_allowed_list: List[str] = []
+        self._user_id: Optional[str] = None
 
         if self._channel == "ibm_cloud":
             self._api_client = RuntimeClient(self._client_params)
@@ -151,6 +220,7 @@ class QiskitRuntimeService:
             # Update client parameters to use authenticated values.
             self._client_params.url = auth_client.current_service_urls()["services"]["runtime"]
             self._client_params.token = auth_client.current_access_token()
+            self._user_id = auth_client.current_user_id()
             self._api_client = RuntimeClient(self._client_params)
             try:
                 self._hgps = self._initialize_hgps(auth_client)
@@ -180,6 +250,7 @@ class QiskitRuntimeService:
         proxies: Optional[ProxyConfiguration] = None,
         verify: Optional[bool] = None,
         channel_strategy: Optional[str] = None,
+        trusted_profile: Optional[str] = None,
     ) -> Account:
         """Discover account."""
         account = None
@@ -212,7 +283,7 @@ class QiskitRuntimeService:
         elif channel:
             if channel and channel not in ["ibm_cloud", "ibm_quantum"]:
                 raise ValueError("'channel' can only be 'ibm_cloud' or 'ibm_quantum'")
-            if token:
+            if token or trusted_profile:
                 account = Account.create_account(
                     channel=channel,
                     token=token,
@@ -221,15 +292,17 @@ class QiskitRuntimeService:
                     proxies=proxies,
                     verify=verify_,
                     channel_strategy=channel_strategy,
+                    trusted_profile=trusted_profile,
                 )
             else:
                 if url:
                     logger.warning("Loading default %s account. Input 'url' is ignored.", channel)
                 account = AccountManager.get(filename=filename, name=name, channel=channel)
-        elif any([token, url]):
+        elif any([token, url, trusted_profile]):
             # Let's not infer based on these attributes as they may change in the future.
             raise ValueError(
-                "'channel' is required if 'token', or 'url' is specified but 'name' is not."
+                "'channel' is required if 'token', 'url' or 'trusted_profile' is specified "
+                "but 'name' is not."
             )
 
         # channel is not defined yet, get it from the AccountManager
@@ -346,6 +419,13 @@ class QiskitRuntimeService:
                 ),
                 proxies=self._account.proxies,
                 verify=self._account.verify,
+                request_hooks=self._client_params.request_hooks,
+                response_hooks=self._client_params.response_hooks,
+                ip_version=self._client_params.ip_version,
+                resolver=self._client_params.resolver,
+                exposed_headers=self._client_params.exposed_headers,
+                cache_storage=self._client_params.cache_storage,
+                compression_threshold=self._client_params.compression_threshold,
             )
 
             # Build the hgp.
@@ -529,19 +609,21 @@ class QiskitRuntimeService:
             else:
                 backend_names = self._backend_allowed_list
                 hgp = None
-            for backend_name in backend_names:
-                if backend := self._create_backend_obj(backend_name, instance=hgp):
-                    backends.append(backend)
         else:
             if instance:
                 raise IBMInputValueError(
                     "The 'instance' keyword is only supported for ``ibm_quantum`` runtime."
                 )
-            for backend_name in self._backend_allowed_list:
-                if backend := self._create_backend_obj(
-                    backend_name, instance=self._account.instance
-                ):
-                    backends.append(backend)
+            backend_names = self._backend_allowed_list
+            hgp = self._account.instance
+        if len(backend_names) > 1:
+            # Fill the configuration cache concurrently rather than one backend at a time.
+            self._api_client.backend_configurations(
+                backend_names, max_workers=BACKEND_CONFIGURATIONS_MAX_WORKERS
+            )
+        for backend_name in backend_names:
+            if backend := self._create_backend_obj(backend_name, instance=hgp):
+                backends.append(backend)
 
         if name:
             kwargs["backend_name"] = name
@@ -563,6 +645,40 @@ class QiskitRuntimeService:
             backend.options.use_fractional_gates = use_fractional_gates
         return filter_backends(backends, filters=filters, **kwargs)
 
+    def backend_configurations(self, instance: Optional[str] = None) -> Dict[str, Any]:
+        """Return the configurations of the backends accessible via this account.
+
+        The configurations are fetched concurrently, which is faster than retrieving the
+        backends when only their configurations are needed.
+
+        Args:
+            instance: This is only supported for ``ibm_quantum`` runtime and is in the
+                hub/group/project format. If given, only the backends of the instance
+                are included.
+
+        Returns:
+            The backend configurations, keyed by backend name.
+
+        Raises:
+            IBMInputValueError: If an instance is given for the ``ibm_cloud`` channel.
+        """
+        if instance and self._channel != "ibm_quantum":
+            raise IBMInputValueError(
+                "The 'instance' keyword is only supported for ``ibm_quantum`` runtime."
+            )
+        if instance:
+            backend_names = self._get_hgp(instance=instance).backends
+        else:
+            backend_names = self._backend_allowed_list
+        raw_configs = self._api_client.backend_configurations(
+            backend_names, max_workers=BACKEND_CONFIGURATIONS_MAX_WORKERS
+        )
+        configurations = {}
+        for name, raw_config in raw_configs.items():
+            if config := configuration_from_server_data(raw_config=raw_config, instance=instance):
+                configurations[name] = config
+        return configurations
+
     def _create_backend_obj(
         self,
         backend_name: str,
@@ -661,6 +777,10 @@ class QiskitRuntimeService:
         channel_strategy: Optional[str] = None,
         set_as_default: Optional[bool] = None,
         private_endpoint: Optional[bool] = False,
+        option_profiles: Optional[Dict[str, Dict[str, Any]]] = None,
+        default_backend: Optional[str] = None,
+        default_options: Optional[Dict[str, Any]] = None,
+        trusted_profile: Optional[str] = None,
     ) -> None:
         """Save the account to disk for future use.
 
@@ -684,6 +804,16 @@ class QiskitRuntimeService:
             set_as_default: If ``True``, the account is saved in filename,
                 as the default account.
             private_endpoint: Connect to private API URL.
+            option_profiles: Named sets of primitive options, for example
+                ``{"fast": {"default_shots": 1000}}``. A profile is applied to a primitive
+                with :meth:`~qiskit_ibm_runtime.base_primitive.BasePrimitiveV2.apply_profile`.
+            default_backend: Name of the backend used by :meth:`backend`, sessions, batches
+                and primitives when no backend is specified.
+            default_options: Primitive options used by the primitives created without
+                options, keyed by primitive, for example
+                ``{"sampler": {"default_shots": 1000}, "estimator": {"default_precision": 0.01}}``.
+            trusted_profile: ID or name of an IBM Cloud trusted profile to authenticate with,
+                instead of a token, from code running in IBM Cloud.
         """
 
         AccountManager.save(
@@ -699,6 +829,10 @@ class QiskitRuntimeService:
             channel_strategy=channel_strategy,
             set_as_default=set_as_default,
             private_endpoint=private_endpoint,
+            option_profiles=option_profiles,
+            default_backend=default_backend,
+            default_options=default_options,
+            trusted_profile=trusted_profile,
         )
 
     @staticmethod
@@ -740,7 +874,7 @@ class QiskitRuntimeService:
         """Return a single backend matching the specified filtering.
 
         Args:
-            name: Name of the backend.
+            name: Name of the backend. Defaults to the default backend of the account.
             instance: This is only supported for ``ibm_quantum`` runtime and is in the
                 hub/group/project format. If an instance is not given, among the providers
                 with access to the backend, a premium provider will be prioritized.
@@ -762,6 +896,7 @@ class QiskitRuntimeService:
             QiskitBackendNotFoundError: if no backend could be found.
         """
         # pylint: disable=arguments-differ, line-too-long
+        name = name or self._account.default_backend
         if not name:
             warnings.warn(
                 (
@@ -782,6 +917,32 @@ class QiskitRuntimeService:
             raise QiskitBackendNotFoundError("No backend matches the criteria." + cloud_msg_url)
         return backends[0]
 
+    def backend_snapshot(self, name: str) -> Dict[str, Any]:
+        """Return the configuration, properties and status of a backend.
+
+        The three are fetched concurrently, which is faster than retrieving the backend
+        and then calling its ``properties()`` and ``status()`` methods. :meth:`backend`
+        itself is unchanged: it still fetches the configuration first, and the properties
+        and status when they are requested from the backend.
+
+        Args:
+            name: Name of the backend.
+
+        Returns:
+            The ``configuration``, ``properties`` and ``status`` of the backend. The
+            properties are ``None`` if the backend has none, like simulators.
+        """
+        snapshot = self._api_client.backend_snapshot(name)
+        return {
+            "configuration": configuration_from_server_data(snapshot["configuration"]),
+            "properties": (
+                properties_from_server_data(snapshot["properties"])
+                if snapshot["properties"]
+                else None
+            ),
+            "status": BackendStatus.from_dict(snapshot["status"]),
+        }
+
     @deprecate_function("get_backend()", "0.24", "Please use backend() instead.", stacklevel=1)
     def get_backend(self, name: str = None, **kwargs: Any) -> Backend:
         """Return a single backend matching the specified filtering."""
@@ -921,6 +1082,7 @@ class QiskitRuntimeService:
                 service=self,
                 version=version,
             )
+        job._submission_stats = response.get("submission_stats")
         return job
 
     def _run(self, *args: Any, **kwargs: Any) -> Union[RuntimeJob, RuntimeJobV2]:
@@ -961,9 +1123,14 @@ class QiskitRuntimeService:
         created_after: Optional[datetime] = None,
         created_before: Optional[datetime] = None,
         descending: bool = True,
-    ) -> List[Union[RuntimeJob, RuntimeJobV2]]:
+        mine_only: bool = False,
+    ) -> PagedList[Union[RuntimeJob, RuntimeJobV2]]:
         """Retrieve all runtime jobs, subject to optional filtering.
 
+        When all the jobs created over more than 30 days are retrieved, with ``limit=None``
+        and ``created_after``, the date range is split in 30-day windows that are fetched
+        concurrently, to stay within the result window size of the server.
+
         Args:
             limit: Number of jobs to retrieve. ``None`` means no limit.
             skip: Starting index for the job retrieval.
@@ -984,9 +1151,14 @@ class QiskitRuntimeService:
                 local date/time.
             descending: If ``True``, return the jobs in descending order of the job
                 creation date (i.e. newest first) until the limit is reached.
+            mine_only: If ``True``, only return jobs submitted by the current user. This is
+                useful on instances shared by several collaborators. This is only supported
+                for ``ibm_quantum`` runtime.
 
         Returns:
-            A list of runtime jobs.
+            A list of runtime jobs. Its ``total_count`` attribute is the number of jobs
+            matching the filters on the server, regardless of ``limit`` and ``skip``, or
+            ``None`` if ``mine_only`` is ``True``.
 
         Raises:
             IBMInputValueError: If an input value is invalid.
@@ -1000,35 +1172,121 @@ class QiskitRuntimeService:
             hub, group, project = from_instance_format(instance)
         if job_tags:
             validate_job_tags(job_tags)
+        if mine_only and not self._user_id:
+            raise IBMInputValueError(
+                "The 'mine_only' keyword is only supported for ``ibm_quantum`` runtime."
+            )
 
+        filters = {
+            "backend_name": backend_name,
+            "pending": pending,
+            "program_id": program_id,
+            "hub": hub,
+            "group": group,
+            "project": project,
+            "job_tags": job_tags,
+            "session_id": session_id,
+            "descending": descending,
+        }
+        windows = self._date_windows(created_after, created_before)
+        if limit is None and not skip and len(windows) > 1:
+            # Long date ranges are split in windows fetched concurrently, to stay within
+            # the result window size of the server. The windows are in creation order,
+            # and their bounds are inclusive, so jobs on a bound are fetched twice.
+            if descending:
+                windows.reverse()
+            with ThreadPoolExecutor(max_workers=JOBS_WINDOW_MAX_WORKERS) as executor:
+                pages = executor.map(
+                    lambda window: self._fetch_jobs(None, 0, *window, mine_only, **filters),
+                    windows,
+                )
+                jobs = {job["id"]: job for page in pages for job in page}
+                job_responses = PagedList(
+                    jobs.values(), total_count=None if mine_only else len(jobs)
+                )
+        else:
+            job_responses = self._fetch_jobs(
+                limit, skip, created_after, created_before, mine_only, **filters
+            )
+
+        return PagedList(
+            [self._decode_job(job) for job in job_responses],
+            total_count=job_responses.total_count,
+        )
+
+    @staticmethod
+    def _date_windows(
+        created_after: Optional[datetime], created_before: Optional[datetime]
+    ) -> List[Tuple[datetime, datetime]]:
+        """Split a job creation date range in windows of ``JOBS_WINDOW_DAYS`` days.
+
+        Args:
+            created_after: Start of the range. If ``None``, the range is not split.
+            created_before: End of the range. Defaults to now.
+
+        Returns:
+            The windows, in chronological order, or a single window if the range is not
+            longer than ``JOBS_WINDOW_DAYS`` days.
+        """
+        if created_after is None:
+            return [(created_after, created_before)]
+        end = created_before or datetime.now(created_after.tzinfo)
+        windows = []
+        start = created_after
+        while end - start > timedelta(days=JOBS_WINDOW_DAYS):
+            windows.append((start, start + timedelta(days=JOBS_WINDOW_DAYS)))
+            start += timedelta(days=JOBS_WINDOW_DAYS)
+        windows.append((start, created_before))
+        return windows
+
+    def _fetch_jobs(
+        self,
+        limit: Optional[int],
+        skip: int,
+        created_after: Optional[datetime],
+        created_before: Optional[datetime],
+        mine_only: bool,
+        **filters: Any,
+    ) -> PagedList[Dict[str, Any]]:
+        """Fetch the pages of jobs matching filters, up to a limit.
+
+        Args:
+            limit: Number of jobs to retrieve. ``None`` means no limit.
+            skip: Starting index for the job retrieval.
+            created_after: Filter by the given start date.
+            created_before: Filter by the given end date.
+            mine_only: Only return jobs submitted by the current user.
+            **filters: Other filters, passed to ``jobs_get``.
+
+        Returns:
+            The jobs, as returned by the server, with the total number of jobs matching
+            the filters, or ``None`` if ``mine_only`` is ``True``.
+        """
         job_responses = []  # type: List[Dict[str, Any]]
         current_page_limit = limit or 20
         offset = skip
+        fetched = 0
 
         while True:
             jobs_response = self._api_client.jobs_get(
                 limit=current_page_limit,
                 skip=offset,
-                backend_name=backend_name,
-                pending=pending,
-                program_id=program_id,
-                hub=hub,
-                group=group,
-                project=project,
-                job_tags=job_tags,
-                session_id=session_id,
                 created_after=created_after,
                 created_before=created_before,
-                descending=descending,
+                **filters,
             )
             job_page = jobs_response["jobs"]
             # count is the total number of jobs that would be returned if
             # there was no limit or skip
             count = jobs_response["count"]
+            fetched += len(job_page)
 
-            job_responses += job_page
+            if mine_only:
+                job_responses += [job for job in job_page if job.get("user_id") == self._user_id]
+            else:
+                job_responses += job_page
 
-            if len(job_responses) == count - skip:
+            if fetched == count - skip or not job_page:
                 # Stop if there are no more jobs returned by the server.
                 break
 
@@ -1042,7 +1300,7 @@ class QiskitRuntimeService:
 
             offset += len(job_page)
 
-        return [self._decode_job(job) for job in job_responses]
+        return PagedList(job_responses, total_count=None if mine_only else count)
 
     def delete_job(self, job_id: str) -> None:
         """Delete a runtime job.
@@ -1063,6 +1321,157 @@ class QiskitRuntimeService:
                 raise RuntimeJobNotFound(f"Job not found: {ex.message}") from None
             raise IBMRuntimeError(f"Failed to delete job: {ex}") from None
 
+    def delete_jobs(
+        self,
+        filters: Optional[Dict[str, Any]] = None,
+        dry_run: bool = True,
+        max_workers: int = DELETE_JOBS_MAX_WORKERS,
+    ) -> Dict[str, Any]:
+        """Delete all finished runtime jobs matching the given filters.
+
+        By default, the matching jobs are only listed. Review them, then call this method
+        again with ``dry_run=False`` to delete them. Note that deleting jobs cannot be
+        reversed. Queued and running jobs are never deleted.
+
+        Example::
+
+            cutoff = datetime(2024, 1, 1)
+            report = service.delete_jobs({"created_before": cutoff})
+            print(f"{len(report['matched'])} jobs would be deleted.")
+            report = service.delete_jobs({"created_before": cutoff}, dry_run=False)
+
+        Args:
+            filters: Filters on the jobs, as accepted by :meth:`jobs`, except ``limit``,
+                ``skip`` and ``pending``.
+            dry_run: If ``True``, only list the jobs that would be deleted.
+            max_workers: Maximum number of jobs deleted concurrently.
+
+        Returns:
+            A report with the following keys:
+
+            * ``matched``: IDs of the jobs matching the filters.
+            * ``deleted``: IDs of the jobs that were deleted. Empty for a dry run.
+            * ``failed``: Error messages of the jobs that could not be deleted, keyed
+              by job ID.
+
+        Raises:
+            IBMInputValueError: If the filters are invalid.
+        """
+        filters = dict(filters or {})
+        unsupported = {"limit", "skip", "pending"}.intersection(filters)
+        if unsupported:
+            raise IBMInputValueError(
+                f"Unsupported filters for deleting jobs: {sorted(unsupported)}"
+            )
+        matched = [job.job_id() for job in self.jobs(limit=None, pending=False, **filters)]
+        report: Dict[str, Any] = {"matched": matched, "deleted": [], "failed": {}}
+        if dry_run or not matched:
+            logger.info("%d jobs match the filters.", len(matched))
+            return report
+
+        with ThreadPoolExecutor(max_workers=max_workers) as executor:
+            futures = {executor.submit(self.delete_job, job_id): job_id for job_id in matched}
+            for future, job_id in futures.items():
+                try:
+                    future.result()
+                    report["deleted"].append(job_id)
+                except Exception as ex:  # pylint: disable=broad-except
+                    report["failed"][job_id] = str(ex)
+        logger.info(
+            "Deleted %d of %d jobs, %d failed.",
+            len(report["deleted"]),
+            len(matched),
+            len(report["failed"]),
+        )
+        return report
+
+    def check_results_available(
+        self, job_id: str, warning_days: float = RESULTS_EXPIRY_WARNING_DAYS
+    ) -> bool:
+        """Check whether the results of a job can still be retrieved.
+
+        Job results are only kept on the server for a limited time. A warning is
+        issued if the results expire within ``warning_days`` days, so that they
+        can be downloaded and saved before they are removed.
+
+        Args:
+            job_id: ID of the job.
+            warning_days: Number of days before the expiration of the results
+                at which a warning is issued.
+
+        Returns:
+            ``True`` if the job completed and its results have not expired,
+            ``False`` otherwise.
+
+        Raises:
+            RuntimeJobNotFound: If the job doesn't exist.
+            IBMRuntimeError: If the request failed.
+        """
+        try:
+            response = self._api_client.job_get(job_id)
+        except RequestsApiError as ex:
+            if ex.status_code == 404:
+                raise RuntimeJobNotFound(f"Job not found: {ex.message}") from None
+            raise IBMRuntimeError(f"Failed to retrieve job: {ex}") from None
+
+        if response.get("state", {}).get("status", "").upper() != "COMPLETED":
+            return False
+        expiration = str_to_utc(response.get("results_expiration"))
+        if expiration is None:
+            return True
+        remaining = expiration - datetime.now(timezone.utc)
+        if remaining <= timedelta(0):
+            return False
+        if remaining <= timedelta(days=warning_days):
+            warnings.warn(
+                f"The results of job {job_id} expire on {expiration.isoformat()}. "
+                "Retrieve and save them before they are removed from the server."
+            )
+        return True
+
+    def sessions(
+        self,
+        limit: Optional[int] = 10,
+        skip: int = 0,
+        pending: Optional[bool] = None,
+        backend_name: Optional[str] = None,
+    ) -> PagedList[Dict[str, Any]]:
+        """Retrieve the details of the sessions of the instance, newest first.
+
+        This can be used to find sessions that were left open, for example::
+
+            for details in service.sessions(limit=None, pending=True):
+                print(details["id"], details["backend_name"], details["state"])
+
+        Args:
+            limit: Number of sessions to retrieve. ``None`` means no limit.
+            skip: Starting index for the session retrieval.
+            pending: Filter by session state. If ``True``, only open sessions are
+                included. If ``False``, only closed sessions are included.
+            backend_name: Name of the backend to retrieve sessions of.
+
+        Returns:
+            The details of the sessions, as returned by the server. Its ``total_count``
+            attribute is the number of sessions matching the filters on the server,
+            regardless of ``limit`` and ``skip``.
+        """
+        session_responses: List[Dict[str, Any]] = []
+        count = None
+        offset = skip
+        while limit is None or len(session_responses) < limit:
+            page_limit = limit - len(session_responses) if limit else 20
+            response = self._api_client.sessions_get(
+                limit=page_limit, skip=offset, pending=pending, backend_name=backend_name
+            )
+            session_page = response["sessions"]
+            count = response["count"]
+            session_responses += session_page
+            offset += len(session_page)
+            if not session_page or offset >= count:
+                # Stop if there are no more sessions returned by the server.
+                break
+        return PagedList(session_responses, total_count=count)
+
     def _decode_job(self, raw_data: Dict) -> Union[RuntimeJob, RuntimeJobV2]:
         """Decode job data received from the server.
 
@@ -1167,16 +1576,417 @@ class QiskitRuntimeService:
             raise QiskitBackendNotFoundError("No backend matches the criteria.")
         return min(candidates, key=lambda b: b.status().pending_jobs)
 
-    def instances(self) -> List[str]:
+    def instances(self, group: Optional[str] = None) -> List[str]:
         """Return the IBM Quantum instances list currently in use for the session.
 
+        Args:
+            group: Only return the instances of a hub, or of a hub and group in the
+                hub/group format. This is only supported for ``ibm_quantum`` runtime.
+
         Returns:
             A list with instances currently in the session.
+
+        Raises:
+            IBMInputValueError: If ``group`` is not in the hub or hub/group format, or is
+                given for ``ibm_cloud`` runtime, whose instances are not grouped in hubs.
         """
+        if group and self._channel == "ibm_cloud":
+            raise IBMInputValueError(
+                "The 'group' keyword is only supported for ``ibm_quantum`` runtime."
+            )
         if self._channel == "ibm_quantum":
-            return list(self._hgps.keys())
+            instances = list(self._hgps.keys())
+            if group:
+                instances = [item for item in instances if in_instance_group(item, group)]
+            return instances
         return []
 
+    def instance_groups(self) -> List[InstanceGroup]:
+        """Return the hubs and groups of the IBM Quantum instances of the session.
+
+        Accounts with access to many projects can use this to browse them by hub and
+        group, for example::
+
+            for group in service.instance_groups():
+                print(group.name, len(group.instances))
+
+        Returns:
+            The groups, in the order of their first instance in :meth:`instances`.
+
+        Raises:
+            IBMInputValueError: If the channel is ``ibm_cloud``, whose instances are not
+                grouped in hubs.
+        """
+        if self._channel == "ibm_cloud":
+            raise IBMInputValueError(
+                "Instance groups are only supported for ``ibm_quantum`` runtime."
+            )
+        groups: Dict[Tuple[str, str], InstanceGroup] = {}
+        for instance in self.instances():
+            hub, group, _ = from_instance_format(instance)
+            groups.setdefault((hub, group), InstanceGroup(hub, group)).instances.append(instance)
+        return list(groups.values())
+
+    def version_info(self) -> Dict[str, Any]:
+        """Return version information about this client and the server it is connected to.
+
+        This information is useful when reporting issues.
+
+        Returns:
+            A dictionary with the following keys:
+
+                * ``client_version``: Version of ``qiskit-ibm-runtime``.
+                * ``qiskit_version``: Version of ``qiskit``, or ``None`` if it cannot be found.
+                * ``python_version``: Version of the Python interpreter.
+                * ``channel``: The channel type used.
+                * ``server_version``: Version information reported by the server, or ``None``
+                  if it is not available. This is only reported for the ``ibm_quantum`` channel.
+        """
+        try:
+            qiskit_version = importlib.metadata.version("qiskit")
+        except importlib.metadata.PackageNotFoundError:
+            qiskit_version = None
+
+        server_version = None
+        if self._channel == "ibm_quantum":
+            try:
+                server_version = VersionClient(
+                    url=self._account.url, **self._client_params.connection_parameters()
+                ).version()
+            except RequestsApiError as ex:
+                logger.warning("Unable to retrieve the server version: %s", ex)
+
+        return {
+            "client_version": __version__,
+            "qiskit_version": qiskit_version,
+            "python_version": platform.python_version(),
+            "channel": self._channel,
+            "server_version": server_version,
+        }
+
+    def warm_up(self) -> None:
+        """Set up the connection to the server eagerly.
+
+        The first request sent to the server has to resolve its host name and perform
+        the TLS handshake. Call this method ahead of latency-sensitive code so that the
+        first real call does not absorb the connection setup cost.
+
+        Raises:
+            IBMNotAuthorizedError: If the credentials are not valid.
+            RequestsApiError: If the request failed.
+        """
+        self._api_client.warm_up()
+
+    def last_response_headers(self) -> Dict[str, str]:
+        """Return selected headers of the last response received from the Qiskit Runtime API.
+
+        The rate limit headers in particular let applications that send many requests
+        adapt their pacing to the hints of the server. The exposed headers can be chosen
+        with the ``exposed_headers`` parameter of the service.
+
+        Returns:
+            The exposed headers present in the last response, keyed by lowercase name.
+        """
+        return self._api_client.last_response_headers()
+
+    def metrics_text(self) -> str:
+        """Return client-side metrics of the requests sent to the Qiskit Runtime API.
+
+        The metrics are in the Prometheus text exposition format, so that a long-running
+        application can expose them to a Prometheus server. They include the number of
+        requests per method, endpoint and status code, and a histogram of the request
+        latencies per method and endpoint. Resource IDs, such as job IDs, are replaced
+        by ``{id}`` in the endpoints.
+
+        Returns:
+            The request metrics.
+        """
+        return self._api_client.metrics_text()
+
+    def fleet_benchmarks(self, **kwargs: Any) -> Dict[str, BackendBenchmarks]:
+        """Return the benchmark figures of the backends accessible via this account.
+
+        The figures include the quantum volume, the CLOPS and the error per layered gate
+        (EPLG) of each backend, so that backends can be compared for device selection.
+
+        Example::
+
+            benchmarks = service.fleet_benchmarks(min_num_qubits=127)
+            best = min(
+                (item for item in benchmarks.values() if item.eplg is not None),
+                key=lambda item: item.eplg,
+            )
+
+        Args:
+            **kwargs: Filters on the backends, as accepted by :meth:`backends`.
+
+        Returns:
+            The benchmark figures, keyed by backend name.
+        """
+        return fleet_benchmarks(self.backends(**kwargs))
+
+    def fleet_monitor(
+        self, interval: float = 60, queue_threshold: Optional[int] = None, **kwargs: Any
+    ) -> FleetMonitor:
+        """Return a monitor of the backends accessible via this account.
+
+        Iterating over the monitor polls the backends and yields an event whenever a
+        backend goes down or comes back up, is recalibrated, or has its queue cross
+        ``queue_threshold``.
+
+        Example::
+
+            for event in service.fleet_monitor(interval=300, queue_threshold=100):
+                print(event.backend_name, event.event_type)
+
+        Args:
+            interval: Number of seconds between polls.
+            queue_threshold: Number of pending jobs above which a backend is reported as
+                having a long queue. If ``None``, queue lengths are not watched.
+            **kwargs: Filters on the backends, as accepted by :meth:`backends`.
+
+        Returns:
+            The fleet monitor.
+        """
+        return FleetMonitor(self.backends(**kwargs), interval, queue_threshold)
+
+    def export_fleet_snapshot(self, path: str, **kwargs: Any) -> str:
+        """Write the configuration, properties and status of the backends to a file.
+
+        The snapshot is a JSON archive recording the state of the fleet at a given time,
+        for example to document the devices used in a publication. The backends are
+        fetched concurrently. A backend that cannot be fetched does not prevent the
+        others from being recorded: its entry in the archive holds the ``error`` instead.
+
+        Example::
+
+            path = service.export_fleet_snapshot("snapshots", operational=True)
+
+        Args:
+            path: Path of the file to write. If it is an existing directory, the file is
+                written in it and named after the time of the snapshot, for example
+                ``fleet_snapshot_20240501T120000Z.json``.
+            **kwargs: Filters on the backends, as accepted by :meth:`backends`.
+
+        Returns:
+            The path of the written file.
+        """
+        names = [backend.name for backend in self.backends(**kwargs)]
+        timestamp = datetime.now(timezone.utc)
+        with ThreadPoolExecutor(max_workers=FLEET_SNAPSHOT_MAX_WORKERS) as executor:
+            snapshots = executor.map(self._fleet_snapshot_entry, names)
+            backends = dict(zip(names, snapshots))
+        if os.path.isdir(path):
+            path = os.path.join(path, f"fleet_snapshot_{timestamp.strftime('%Y%m%dT%H%M%SZ')}.json")
+        archive = {
+            "timestamp": timestamp.isoformat(),
+            "client_version": __version__,
+            "channel": self._channel,
+            "backends": backends,
+        }
+        with open(path, "w", encoding="utf-8") as file:
+            json.dump(archive, file, cls=RuntimeEncoder, indent=2)
+        return path
+
+    def _fleet_snapshot_entry(self, name: str) -> Dict[str, Any]:
+        """Return the snapshot of a backend, or the error raised while fetching it."""
+        try:
+            return self._api_client.backend_snapshot(name)
+        except Exception as ex:  # pylint: disable=broad-except
+            logger.warning("Unable to retrieve the snapshot of backend %s: %s", name, ex)
+            return {"error": f"{type(ex).__name__}: {ex}"}
+
+    @property
+    def default_backend(self) -> Optional[str]:
+        """Name of the backend used when none is specified, saved with the account."""
+        return self._account.default_backend
+
+    @property
+    def default_options(self) -> Dict[str, Any]:
+        """Primitive options used when none are specified, keyed by primitive and saved with
+        the account."""
+        return copy.deepcopy(self._account.default_options or {})
+
+    def option_profile(self, name: str) -> Dict[str, Any]:
+        """Return a named set of primitive options saved with the account.
+
+        Args:
+            name: Name of the profile.
+
+        Returns:
+            The options of the profile.
+
+        Raises:
+            IBMInputValueError: If the account does not have a profile with this name.
+        """
+        profiles = self._account.option_profiles or {}
+        if name not in profiles:
+            raise IBMInputValueError(
+                f"The account does not have an option profile named '{name}'. "
+                f"Available profiles: {sorted(profiles)}."
+            )
+        return copy.deepcopy(profiles[name])
+
+    def usage(self) -> Dict[str, Any]:
+        """Return the usage of the instance in the current usage period.
+
+        Returns:
+            Usage of the instance, including the consumed, limit and remaining usage
+            in seconds, and the start and end of the usage period.
+
+        Raises:
+            IBMInputValueError: If the channel is not ``ibm_cloud``.
+        """
+        if self._channel != "ibm_cloud":
+            raise IBMInputValueError("The usage is only available for ``ibm_cloud`` runtime.")
+        return self._api_client.usage()
+
+    def usage_by_collaborator(
+        self,
+        instance: Optional[str] = None,
+        created_after: Optional[datetime] = None,
+        created_before: Optional[datetime] = None,
+    ) -> List[Dict[str, Any]]:
+        """Return the usage of an instance per collaborator.
+
+        All the pages of jobs of the instance are retrieved, and the usage of the jobs is
+        aggregated per user, so that admins of shared instances can see who consumes it.
+
+        Note:
+            The API does not report the usage per user, so it is computed from the jobs
+            that the account can see. It therefore only includes the jobs that have not
+            been deleted, and it takes one request per page of jobs, which can be slow for
+            instances with many jobs. Use ``created_after`` and ``created_before`` to limit
+            the jobs retrieved.
+
+        Args:
+            instance: This is only supported for ``ibm_quantum`` runtime and is in the
+                hub/group/project format.
+            created_after: Only include jobs created after this local date/time.
+            created_before: Only include jobs created before this local date/time.
+
+        Returns:
+            One entry per user, with the ``user_id``, the number of ``jobs`` and their total
+            ``quantum_seconds``, sorted by decreasing usage.
+
+        Raises:
+            IBMInputValueError: If the instance is invalid.
+        """
+        hub = group = project = None
+        if instance:
+            if self._channel == "ibm_cloud":
+                raise IBMInputValueError(
+                    "The 'instance' keyword is only supported for ``ibm_quantum`` runtime."
+                )
+            hub, group, project = from_instance_format(instance)
+
+        totals: Dict[str, Dict[str, Any]] = {}
+        for job in self._job_pages(
+            hub=hub,
+            group=group,
+            project=project,
+            created_after=created_after,
+            created_before=created_before,
+        ):
+            user_id = job.get("user_id") or "unknown"
+            usage = job.get("usage") or {}
+            entry = totals.setdefault(
+                user_id, {"user_id": user_id, "jobs": 0, "quantum_seconds": 0}
+            )
+            entry["jobs"] += 1
+            entry["quantum_seconds"] += usage.get("quantum_seconds") or 0
+
+        return sorted(totals.values(), key=lambda entry: entry["quantum_seconds"], reverse=True)
+
+    def _job_pages(self, **filters: Any) -> Iterator[Dict[str, Any]]:
+        """Iterate over the responses of all the jobs matching the filters, page by page.
+
+        Args:
+            **filters: Filters of the jobs, as accepted by ``RuntimeClient.jobs_get()``.
+
+        Yields:
+            The job responses.
+        """
+        offset = 0
+        while True:
+            response = self._api_client.jobs_get(limit=USAGE_PAGE_SIZE, skip=offset, **filters)
+            page = response["jobs"]
+            yield from page
+            offset += len(page)
+            if not page or offset >= response["count"]:
+                break
+
+    def forecast_usage(self, history_days: int = 7) -> UsageForecast:
+        """Project the usage of the instance until the end of the usage period.
+
+        The daily usage of the jobs created in the last ``history_days`` days is used to
+        project when the usage limit is reached, and the remaining usage is divided over
+        the days left in the period to give a daily budget. This helps to pace a limited
+        quota, for example the minutes of the Open Plan.
+
+        Args:
+            history_days: Number of recent days whose usage the projection is based on.
+
+        Returns:
+            The usage forecast.
+
+        Raises:
+            IBMInputValueError: If the channel is not ``ibm_cloud``, or ``history_days`` is
+                not positive.
+        """
+        if history_days <= 0:
+            raise IBMInputValueError("The number of history days must be positive.")
+        usage = self.usage()
+        now = datetime.now(timezone.utc)
+        history_start = now - timedelta(days=history_days)
+        period_start = str_to_utc((usage.get("usage_period") or {}).get("start_time"))
+        if period_start is not None and period_start > history_start:
+            # Usage from the previous period does not count against the current limit.
+            history_start = period_start
+        recent_daily_rate = None
+        days = (now - history_start).total_seconds() / SECONDS_PER_DAY
+        if days > 0:
+            recent_seconds = sum(
+                (job.get("usage") or {}).get("quantum_seconds") or 0
+                for job in self._job_pages(created_after=history_start)
+            )
+            recent_daily_rate = recent_seconds / days
+        return forecast_usage(usage, now=now, recent_daily_rate=recent_daily_rate)
+
+    def support_bundle(self, path: str) -> None:
+        """Write a diagnostic bundle to help the triage of issues.
+
+        The bundle is a JSON file with the version information, the account settings,
+        the backends and instances available, the most recent requests sent to the server
+        and the details of the last failed request. Credentials and query strings are
+        never included, so the bundle can be attached to an issue report.
+
+        Args:
+            path: Path of the file to write.
+        """
+        api_session = getattr(self._api_client, "_session", None)
+        bundle = {
+            "created": datetime.now(timezone.utc).isoformat(),
+            "version_info": self.version_info(),
+            "account": {
+                "channel": self._account.channel,
+                "url": self._account.url,
+                "instance": self._account.instance,
+                "private_endpoint": self._account.private_endpoint,
+                "verify": self._account.verify,
+                "proxies_configured": self._account.proxies is not None,
+            },
+            "capabilities": {
+                "channel_strategy": self._channel_strategy,
+                "instances": self.instances(),
+                "backends": self._backend_allowed_list,
+            },
+            "recent_requests": list(getattr(api_session, "recent_requests", [])),
+            "last_error": getattr(api_session, "last_error", None),
+        }
+        with open(path, "w", encoding="utf-8") as bundle_file:
+            json.dump(bundle, bundle_file, indent=2, default=str)
+
     @property
     def channel(self) -> str:
         """Return the channel type used.
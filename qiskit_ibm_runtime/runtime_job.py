@@ -12,7 +12,7 @@
 
 """Qiskit runtime job."""
 
-from typing import Any, Optional, Callable, Dict, Type, Union, Sequence, List
+from typing import Any, Optional, Callable, Dict, Type, Union, Sequence, List, Tuple
 from concurrent import futures
 import logging
 import time
@@ -318,7 +318,9 @@ class RuntimeJob(Job, BaseRuntimeJob):
     def wait_for_final_state(  # pylint: disable=arguments-differ
         self,
         timeout: Optional[float] = None,
-    ) -> None:
+        poll_interval: float = 0.1,
+        return_metrics: bool = False,
+    ) -> Union[JobStatus, Tuple[JobStatus, Dict[str, Any]]]:
         """Use the websocket server to wait for the final the state of a job.
 
         The server will remain open if the job is still running and the connection will
@@ -326,31 +328,36 @@ class RuntimeJob(Job, BaseRuntimeJob):
 
         Args:
             timeout: Seconds to wait for the job. If ``None``, wait indefinitely.
+            poll_interval: Seconds to wait before polling the status again, once the
+                websocket connection is closed. The interval doubles after each poll,
+                up to 5 seconds, or up to ``poll_interval`` if it is longer.
+            return_metrics: Whether to also return the final metrics of the job, as
+                returned by :meth:`metrics`, once its status is final.
+
+        Returns:
+            The final status of the job, or a tuple of the final status and metrics of
+            the job if ``return_metrics`` is ``True``.
 
         Raises:
             RuntimeJobTimeoutError: If the job does not complete within given timeout.
         """
         try:
-            start_time = time.time()
+            start_time = time.monotonic()
             if self._status not in self.JOB_FINAL_STATES and not self._is_streaming():
                 self._ws_client_future = self._executor.submit(self._start_websocket_client)
             if self._is_streaming():
                 self._ws_client_future.result(timeout)
             # poll for status after stream has closed until status is final
             # because status doesn't become final as soon as stream closes
-            status = self.status()
-            while status not in self.JOB_FINAL_STATES:
-                elapsed_time = time.time() - start_time
-                if timeout is not None and elapsed_time >= timeout:
-                    raise RuntimeJobTimeoutError(
-                        f"Timed out waiting for job to complete after {timeout} secs."
-                    )
-                time.sleep(0.1)
-                status = self.status()
+            status = self._poll_for_final_state(start_time, timeout, poll_interval)
         except futures.TimeoutError:
             raise RuntimeJobTimeoutError(
-                f"Timed out waiting for job to complete after {timeout} secs."
+                f"Timed out waiting for job to complete after {timeout} secs. "
+                f"Last status: {self._status}."
             )
+        if return_metrics:
+            return status, self.metrics()
+        return status
 
     def backend(self, timeout: Optional[float] = None) -> Optional[Backend]:
         """Return the backend where this job was executed. Retrieve data again if backend is None.
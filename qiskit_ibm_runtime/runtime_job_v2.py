@@ -31,7 +31,8 @@ from .exceptions import (
     RuntimeJobMaxTimeoutError,
     RuntimeJobTimeoutError,
 )
-from .utils.result_decoder import ResultDecoder
+from .utils.result_decoder import ResultDecoder, select_pub_results
+from .utils.shot_splitting import merge_sampler_results, split_pub_indices
 from .api.clients import RuntimeClient
 from .api.exceptions import RequestsApiError
 from .api.client_parameters import ClientParameters
@@ -109,6 +110,7 @@ class RuntimeJobV2(BasePrimitiveJob[PrimitiveResult, JobStatus], BaseRuntimeJob)
             version=version,
         )
         self._status: JobStatus = "INITIALIZING"
+        self._shots_splits: Optional[List[List[Optional[int]]]] = None
         if user_callback is not None:
             self.stream_results(user_callback)
 
@@ -116,12 +118,19 @@ class RuntimeJobV2(BasePrimitiveJob[PrimitiveResult, JobStatus], BaseRuntimeJob)
         self,
         timeout: Optional[float] = None,
         decoder: Optional[Type[ResultDecoder]] = None,
+        pub_indices: Optional[Sequence[int]] = None,
     ) -> Any:
         """Return the results of the job.
 
         Args:
             timeout: Number of seconds to wait for job.
             decoder: A :class:`ResultDecoder` subclass used to decode job results.
+            pub_indices: Indices of the PUBs whose results should be returned. Only the
+                results of these PUBs are decoded, which is faster for jobs with many PUBs.
+                If ``None``, the results of all PUBs are returned.
+
+        If the PUBs of this job were split across the maximum number of shots of the
+        backend, the results of the parts of each PUB are merged back together.
 
         Returns:
             Runtime job result.
@@ -146,8 +155,17 @@ class RuntimeJobV2(BasePrimitiveJob[PrimitiveResult, JobStatus], BaseRuntimeJob)
         result_raw = self._download_external_result(
             self._api_client.job_results(job_id=self.job_id())
         )
-
-        return _decoder.decode(result_raw) if result_raw else None  # type: ignore
+        if not result_raw:
+            return None
+        if self._shots_splits is None:
+            if pub_indices is not None:
+                result_raw = select_pub_results(result_raw, pub_indices)
+            return _decoder.decode(result_raw)  # type: ignore
+
+        split_indices, splits = split_pub_indices(self._shots_splits, pub_indices)
+        if pub_indices is not None:
+            result_raw = select_pub_results(result_raw, split_indices)
+        return merge_sampler_results(_decoder.decode(result_raw), splits)  # type: ignore
 
     def cancel(self) -> None:
         """Cancel the job.
@@ -235,7 +253,9 @@ class RuntimeJobV2(BasePrimitiveJob[PrimitiveResult, JobStatus], BaseRuntimeJob)
     def wait_for_final_state(  # pylint: disable=arguments-differ
         self,
         timeout: Optional[float] = None,
-    ) -> None:
+        poll_interval: float = 0.1,
+        return_metrics: bool = False,
+    ) -> Union[JobStatus, Tuple[JobStatus, Dict[str, Any]]]:
         """Use the websocket server to wait for the final the state of a job.
 
         The server will remain open if the job is still running and the connection will
@@ -243,31 +263,36 @@ class RuntimeJobV2(BasePrimitiveJob[PrimitiveResult, JobStatus], BaseRuntimeJob)
 
         Args:
             timeout: Seconds to wait for the job. If ``None``, wait indefinitely.
+            poll_interval: Seconds to wait before polling the status again, once the
+                websocket connection is closed. The interval doubles after each poll,
+                up to 5 seconds, or up to ``poll_interval`` if it is longer.
+            return_metrics: Whether to also return the final metrics of the job, as
+                returned by :meth:`metrics`, once its status is final.
+
+        Returns:
+            The final status of the job, or a tuple of the final status and metrics of
+            the job if ``return_metrics`` is ``True``.
 
         Raises:
             RuntimeJobTimeoutError: If the job does not complete within given timeout.
         """
         try:
-            start_time = time.time()
+            start_time = time.monotonic()
             if self._status not in self.JOB_FINAL_STATES and not self._is_streaming():
                 self._ws_client_future = self._executor.submit(self._start_websocket_client)
             if self._is_streaming():
                 self._ws_client_future.result(timeout)
             # poll for status after stream has closed until status is final
             # because status doesn't become final as soon as stream closes
-            status = self.status()
-            while status not in self.JOB_FINAL_STATES:
-                elapsed_time = time.time() - start_time
-                if timeout is not None and elapsed_time >= timeout:
-                    raise RuntimeJobTimeoutError(
-                        f"Timed out waiting for job to complete after {timeout} secs."
-                    )
-                time.sleep(0.1)
-                status = self.status()
+            status = self._poll_for_final_state(start_time, timeout, poll_interval)
         except futures.TimeoutError:
             raise RuntimeJobTimeoutError(
-                f"Timed out waiting for job to complete after {timeout} secs."
+                f"Timed out waiting for job to complete after {timeout} secs. "
+                f"Last status: {self._status}."
             )
+        if return_metrics:
+            return status, self.metrics()
+        return status
 
     def backend(self, timeout: Optional[float] = None) -> Optional[Backend]:
         """Return the backend where this job was executed. Retrieve data again if backend is None.
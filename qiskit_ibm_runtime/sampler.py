@@ -27,6 +27,7 @@ from .options import Options
 from .runtime_job import RuntimeJob
 from .runtime_job_v2 import RuntimeJobV2
 from .ibm_backend import IBMBackend
+from .qiskit_runtime_service import QiskitRuntimeService
 from .base_primitive import BasePrimitiveV1, BasePrimitiveV2
 
 # pylint: disable=unused-import,cyclic-import
@@ -36,6 +37,8 @@ from .utils.deprecation import deprecate_arguments, issue_deprecation_msg
 from .utils.qctrl import validate as qctrl_validate
 from .utils.qctrl import validate_v2 as qctrl_validate_v2
 from .utils import validate_classical_registers
+from .utils.shot_splitting import split_sampler_pubs
+from .utils.openqasm3 import coerce_qasm3_pubs
 from .options import SamplerOptions
 
 logger = logging.getLogger(__name__)
@@ -126,15 +129,28 @@ class SamplerV2(BasePrimitiveV2[SamplerOptions], Sampler, BaseSamplerV2):
             mode = session if backend and session else backend if backend else session
         BasePrimitiveV2.__init__(self, mode=mode, options=options)
 
-    def run(self, pubs: Iterable[SamplerPubLike], *, shots: int | None = None) -> RuntimeJobV2:
+    def run(
+        self,
+        pubs: Iterable[SamplerPubLike],
+        *,
+        shots: int | None = None,
+        split_shots: bool = False,
+    ) -> RuntimeJobV2:
         """Submit a request to the sampler primitive.
 
         Args:
             pubs: An iterable of pub-like objects. For example, a list of circuits
-                  or tuples ``(circuit, parameter_values)``.
+                  or tuples ``(circuit, parameter_values)``. Circuits can also be given as
+                  OpenQASM 3 program strings, which requires the ``qiskit-qasm3-import`` package.
             shots: The total number of shots to sample for each sampler pub that does
                    not specify its own shots. If ``None``, the primitive's default
                    shots value will be used, which can vary by implementation.
+            split_shots: If ``True``, pubs requesting more shots than the maximum allowed
+                   by the backend are split into several pubs within the limit. The
+                   results of the parts are merged back by ``job.result()``, and the
+                   shots of each part are reported in the ``shots_split`` metadata
+                   field of the merged pub result. Results retrieved from a job
+                   fetched again with ``QiskitRuntimeService.job()`` are not merged.
 
         Returns:
             Submitted job.
@@ -144,11 +160,35 @@ class SamplerV2(BasePrimitiveV2[SamplerOptions], Sampler, BaseSamplerV2):
         Raises:
             ValueError: Invalid arguments are given.
         """
-        coerced_pubs = [SamplerPub.coerce(pub, shots) for pub in pubs]
+        coerced_pubs = [SamplerPub.coerce(pub, shots) for pub in coerce_qasm3_pubs(pubs)]
 
         validate_classical_registers(coerced_pubs)
 
-        return self._run(coerced_pubs)  # type: ignore[arg-type]
+        max_shots = self._max_shots() if split_shots else None
+        if not max_shots:
+            return self._run(coerced_pubs)  # type: ignore[arg-type]
+
+        split_pubs, splits = split_sampler_pubs(coerced_pubs, max_shots)
+        if len(split_pubs) == len(coerced_pubs):
+            return self._run(coerced_pubs)  # type: ignore[arg-type]
+        logger.info(
+            "Split %d pubs into %d pubs of at most %d shots.",
+            len(coerced_pubs),
+            len(split_pubs),
+            max_shots,
+        )
+        job = self._run(split_pubs)  # type: ignore[arg-type]
+        job._shots_splits = splits
+        return job
+
+    def _max_shots(self) -> Optional[int]:
+        """Return the maximum number of shots of a pub allowed by the backend, if known."""
+        if not isinstance(self._service, QiskitRuntimeService) or not isinstance(
+            self._backend, IBMBackend
+        ):
+            # Results are only merged back by jobs submitted to Qiskit Runtime.
+            return None
+        return getattr(self._backend.configuration(), "max_shots", None)
 
     def _validate_options(self, options: dict) -> None:
         """Validate that primitive inputs (options) are valid
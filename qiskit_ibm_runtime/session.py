@@ -14,9 +14,13 @@
 
 from __future__ import annotations
 
-from typing import Dict, Optional, Type, Union, Callable, Any
+from typing import Dict, List, Optional, Type, Union, Callable, Any, Sequence, Tuple
+from datetime import datetime
 from types import TracebackType
 from functools import wraps
+import logging
+import threading
+import time
 import warnings
 
 from qiskit.providers.backend import BackendV1, BackendV2
@@ -24,13 +28,19 @@ from qiskit.providers.backend import BackendV1, BackendV2
 from qiskit_ibm_runtime import QiskitRuntimeService
 from .exceptions import IBMInputValueError, IBMRuntimeError
 from .runtime_job import RuntimeJob
-from .runtime_job_v2 import RuntimeJobV2
+from .runtime_job_v2 import API_TO_JOB_STATUS, RuntimeJobV2
 from .utils.result_decoder import ResultDecoder
 from .ibm_backend import IBMBackend
 from .utils.default_session import set_cm_session
 from .utils.deprecation import issue_deprecation_msg
-from .utils.converters import hms_to_seconds
-from .fake_provider.local_service import QiskitRuntimeLocalService
+from .utils.converters import hms_to_seconds, str_to_utc
+from .utils.session_state import SessionState
+from .fake_provider.local_service import QiskitRuntimeLocalService, is_local_backend_name
+
+logger = logging.getLogger(__name__)
+
+IDLE_WARNING_FRACTION = 0.8
+"""Fraction of the idle timeout after which the idle callback is invoked."""
 
 
 def _active_session(func):  # type: ignore
@@ -83,11 +93,19 @@ class Session:
             print(f"Counts: {pub_result.data.cr.get_counts()}")
     """
 
+    _mode = "dedicated"
+    """Execution mode sent to the server when the session is created."""
+
     def __init__(
         self,
-        service: Optional[QiskitRuntimeService] = None,
-        backend: Optional[Union[str, BackendV1, BackendV2]] = None,
+        service: Optional[Union[QiskitRuntimeService, QiskitRuntimeLocalService]] = None,
+        backend: Optional[
+            Union[str, BackendV1, BackendV2, Sequence[Union[str, IBMBackend]]]
+        ] = None,
         max_time: Optional[Union[int, str]] = None,
+        idle_timeout: Optional[Union[int, str]] = None,
+        idle_callback: Optional[Callable[["Session", float], None]] = None,
+        state_check_interval: Optional[Union[int, str]] = None,
     ):  # pylint: disable=line-too-long
         """Session constructor.
 
@@ -95,9 +113,15 @@ class Session:
             service: Optional instance of the ``QiskitRuntimeService`` class.
                 If ``None``, the service associated with the backend, if known, is used.
                 Otherwise ``QiskitRuntimeService()`` is used to initialize
-                your default saved account.
+                your default saved account. A ``QiskitRuntimeLocalService`` enables local
+                testing mode, in which the session is not created on the server.
             backend: Optional instance of ``Backend`` class or string name of backend.
-                If not specified, a backend will be selected automatically (IBM Cloud channel only).
+                If not specified, the default backend of the account is used.
+                A list of IBM backends or backend names can also be given, in priority order.
+                The session is then created on the first operational backend of the list,
+                which is returned by :meth:`backend`. If no ``service`` is given, the names
+                of fake backends, such as ``fake_manila``, and ``aer_simulator`` select local
+                testing mode, without any API call.
 
             max_time:
                 Maximum amount of time, a runtime session can be open before being
@@ -106,22 +130,56 @@ class Session:
                 `system imposed maximum
                 <https://docs.quantum.ibm.com/run/max-execution-time>`_.
 
+            idle_timeout: Close the session automatically if no job is submitted to it
+                for this amount of time, to avoid being billed for an idle dedicated session.
+                Can be specified as seconds (int) or a string like "10m". The timer starts
+                when the session is created and is reset by every job submission. It does not
+                apply to local testing mode or simulators, where no session is created on the
+                server.
+
+            idle_callback: Callable invoked once the session has been idle for 80% of
+                ``idle_timeout``, with the session and the number of seconds left before
+                it is closed. Submitting a job resets the timer. If not specified, a
+                warning is logged instead.
+
+            state_check_interval: Check the state of the session at this interval, by
+                fetching its details, while it is open. A warning is logged if the session
+                was closed by the server, for example after its interactive timeout, and the
+                checks stop. The checks do not count as activity, so they do not extend the
+                interactive timeout. Can be specified as seconds (int) or a string like "5m".
+
         Raises:
             ValueError: If an input value is invalid.
+            IBMInputValueError: If the name of a backend of local testing mode is given with a
+                ``QiskitRuntimeService``.
         """
         self._service: Optional[QiskitRuntimeService | QiskitRuntimeLocalService] = None
         self._backend: Optional[BackendV1 | BackendV2] = None
         self._instance = None
         self._active = True
         self._session_id = None
+        self._state: Optional[SessionState] = None
 
         self._service = service
+        if isinstance(backend, (list, tuple)):
+            backend = self._select_operational_backend(backend)
         if isinstance(backend, IBMBackend):
             self._service = self._service or backend.service
             self._backend = backend
         elif isinstance(backend, (BackendV1, BackendV2)):
             self._service = QiskitRuntimeLocalService()
             self._backend = backend
+        elif isinstance(backend, str) and (
+            isinstance(self._service, QiskitRuntimeLocalService)
+            or (self._service is None and is_local_backend_name(backend))
+        ):
+            self._service = self._service or QiskitRuntimeLocalService()
+            self._backend = self._service.backend(backend)
+        elif isinstance(backend, str) and is_local_backend_name(backend):
+            raise IBMInputValueError(
+                f"{backend} is a backend of local testing mode, which cannot be used with "
+                f"{type(self._service).__name__}. Use a QiskitRuntimeLocalService instead."
+            )
         else:
             if not self._service:
                 self._service = (
@@ -129,6 +187,8 @@ class Session:
                     if QiskitRuntimeService.global_service is None
                     else QiskitRuntimeService.global_service
                 )
+            if backend is None:
+                backend = getattr(self._service, "default_backend", None)
             if isinstance(backend, str):
                 self._backend = self._service.backend(backend)
             elif backend is None:
@@ -141,17 +201,148 @@ class Session:
             if max_time is None or isinstance(max_time, int)
             else hms_to_seconds(max_time, "Invalid max_time value: ")
         )
+        if self._max_time is not None and self._max_time <= 0:
+            raise ValueError(f"Invalid max_time value: {max_time}. It must be positive.")
+        self._idle_timeout = (
+            idle_timeout
+            if idle_timeout is None or isinstance(idle_timeout, int)
+            else hms_to_seconds(idle_timeout, "Invalid idle_timeout value: ")
+        )
+        if self._idle_timeout is not None and self._idle_timeout <= 0:
+            raise ValueError(f"Invalid idle_timeout value: {idle_timeout}. It must be positive.")
+        self._state_check_interval = (
+            state_check_interval
+            if state_check_interval is None or isinstance(state_check_interval, int)
+            else hms_to_seconds(state_check_interval, "Invalid state_check_interval value: ")
+        )
+        if self._state_check_interval is not None and self._state_check_interval <= 0:
+            raise ValueError(
+                f"Invalid state_check_interval value: {state_check_interval}. It must be positive."
+            )
 
         if isinstance(self._backend, IBMBackend):
             self._instance = self._backend._instance
             if not self._backend.configuration().simulator:
                 self._session_id = self._create_session()
 
+        self._idle_callback = idle_callback
+        self._last_submission = time.monotonic()
+        self._stop_watchers = threading.Event()
+        # The watchers only apply to sessions created on the server.
+        if self._session_id and isinstance(self._service, QiskitRuntimeService):
+            if self._idle_timeout is not None:
+                threading.Thread(target=self._watch_idle, daemon=True).start()
+            if self._state_check_interval is not None:
+                threading.Thread(target=self._check_state, daemon=True).start()
+
+    def _select_operational_backend(
+        self, candidates: Sequence[Union[str, IBMBackend]]
+    ) -> IBMBackend:
+        """Return the first operational backend among prioritized candidates.
+
+        Args:
+            candidates: IBM backends or backend names, in priority order.
+
+        Returns:
+            The first operational backend.
+
+        Raises:
+            ValueError: If no candidate is given, a candidate is not an IBM backend,
+                or none of the candidates is operational.
+        """
+        if not candidates:
+            raise ValueError('"backend" is required')
+        for index, candidate in enumerate(candidates):
+            if isinstance(candidate, str):
+                if not self._service:
+                    self._service = (
+                        QiskitRuntimeService()
+                        if QiskitRuntimeService.global_service is None
+                        else QiskitRuntimeService.global_service
+                    )
+                candidate = self._service.backend(candidate)
+            if not isinstance(candidate, IBMBackend):
+                raise ValueError(
+                    f"Invalid backend type {type(candidate)}. Only IBM backends can be "
+                    "given as a list of fallback backends."
+                )
+            if candidate.status().operational:
+                if index:
+                    logger.warning(
+                        "Backend %s is not operational. The session is created on %s instead.",
+                        self._backend_name(candidates[0]),
+                        candidate.name,
+                    )
+                return candidate
+        raise ValueError(
+            "None of the backends "
+            f"{[self._backend_name(candidate) for candidate in candidates]} is operational."
+        )
+
+    @staticmethod
+    def _backend_name(backend: Union[str, IBMBackend]) -> str:
+        """Return the name of a backend or backend name."""
+        return backend if isinstance(backend, str) else backend.name
+
+    def _watch_idle(self) -> None:
+        """Close the session once no job was submitted for ``idle_timeout`` seconds."""
+        warn_after = self._idle_timeout * IDLE_WARNING_FRACTION
+        warned_for = None
+        while self._active and not self._stop_watchers.is_set():
+            last_submission = self._last_submission
+            idle = time.monotonic() - last_submission
+            if idle >= self._idle_timeout:
+                logger.warning(
+                    "Session %s was idle for %s seconds and is being closed.",
+                    self._session_id,
+                    self._idle_timeout,
+                )
+                self.close()
+                return
+            if idle >= warn_after and warned_for != last_submission:
+                warned_for = last_submission
+                remaining = self._idle_timeout - idle
+                if self._idle_callback:
+                    try:
+                        self._idle_callback(self, remaining)
+                    except Exception:  # pylint: disable=broad-except
+                        logger.exception(
+                            "The idle callback of session %s raised an exception.",
+                            self._session_id,
+                        )
+                else:
+                    logger.warning(
+                        "Session %s is idle and will be closed in %d seconds "
+                        "unless a job is submitted.",
+                        self._session_id,
+                        remaining,
+                    )
+                continue
+            deadline = warn_after if warned_for != last_submission else self._idle_timeout
+            self._stop_watchers.wait(deadline - idle)
+
+    def _check_state(self) -> None:
+        """Check the session every ``state_check_interval`` seconds until it is closed."""
+        while not self._stop_watchers.wait(self._state_check_interval) and self._active:
+            try:
+                response = self._service._api_client.session_details(self._session_id)
+            except Exception as ex:  # pylint: disable=broad-except
+                logger.debug("Unable to check session %s: %s", self._session_id, ex)
+                continue
+            if response:
+                self._record_state(response.get("state"))
+            if self._state == SessionState.CLOSED:
+                logger.warning(
+                    "Session %s was closed by the server. New jobs will not run in it.",
+                    self._session_id,
+                )
+                return
+
     def _create_session(self) -> Optional[str]:
         """Create a session."""
         if isinstance(self._service, QiskitRuntimeService):
             session = self._service._api_client.create_session(
-                self.backend(), self._instance, self._max_time, self._service.channel, "dedicated"
+                self.backend(), self._instance, self._max_time, self._service.channel, self._mode
             )
             return session.get("id")
         return None
@@ -193,6 +384,7 @@ class Session:
             options["instance"] = self._instance
 
         options["backend"] = self._backend
+        self._last_submission = time.monotonic()
 
         if isinstance(self._service, QiskitRuntimeService):
             job = self._service.run(
@@ -224,6 +416,7 @@ class Session:
     def cancel(self) -> None:
         """Cancel all pending jobs in a session."""
         self._active = False
+        self._stop_watchers.set()
         if self._session_id and isinstance(self._service, QiskitRuntimeService):
             self._service._api_client.cancel_session(self._session_id)
 
@@ -232,9 +425,29 @@ class Session:
         queued or running jobs will run to completion. The session will be terminated once there
         are no more pending jobs."""
         self._active = False
+        self._stop_watchers.set()
         if self._session_id and isinstance(self._service, QiskitRuntimeService):
             self._service._api_client.close_session(self._session_id)
 
+    @_active_session
+    def pause(self) -> None:
+        """Stop the session from accepting new jobs, without closing it.
+
+        Jobs already submitted to the session keep running. New jobs are rejected by the
+        server until :meth:`resume` is called.
+        """
+        self._set_accepting_jobs(False)
+
+    @_active_session
+    def resume(self) -> None:
+        """Let the session accept new jobs again, after :meth:`pause`."""
+        self._set_accepting_jobs(True)
+
+    def _set_accepting_jobs(self, accepting_jobs: bool) -> None:
+        """Update whether the session accepts new jobs, if it was created on the server."""
+        if self._session_id and isinstance(self._service, QiskitRuntimeService):
+            self._service._api_client.update_session(self._session_id, accepting_jobs)
+
     def backend(self) -> Optional[str]:
         """Return backend for this session.
 
@@ -272,6 +485,68 @@ class Session:
 
         return None
 
+    def jobs(self, limit: Optional[int] = None) -> List[Union[RuntimeJob, RuntimeJobV2]]:
+        """Return the jobs submitted in this session, newest first.
+
+        Args:
+            limit: Number of jobs to retrieve. ``None`` means no limit.
+
+        Returns:
+            The jobs of the session.
+        """
+        if not self._session_id or not isinstance(self._service, QiskitRuntimeService):
+            return []
+        return self._service.jobs(limit=limit, session_id=self._session_id)
+
+    def job_statuses(self) -> Dict[str, str]:
+        """Return the status of each job submitted in this session.
+
+        The statuses are read from the list of jobs of the session, without retrieving each
+        job, so that the progress of large sessions can be polled cheaply.
+
+        Returns:
+            The status of each job, such as ``QUEUED``, ``RUNNING`` or ``DONE``, keyed by
+            job ID, newest job first.
+        """
+        if not self._session_id or not isinstance(self._service, QiskitRuntimeService):
+            return {}
+        job_responses = self._service._fetch_jobs(
+            None, 0, None, None, False, session_id=self._session_id
+        )
+        statuses = {}
+        for job in job_responses:
+            status = job["state"]["status"].upper()
+            statuses[job["id"]] = API_TO_JOB_STATUS.get(status, status)
+        return statuses
+
+    def state(self) -> Optional[SessionState]:
+        """Return the current state of the session.
+
+        Returns:
+            The state of the session, or ``None`` if it is not available.
+        """
+        details = self.details()
+        return SessionState.parse(details["state"]) if details else None
+
+    def _record_state(self, state: Optional[str]) -> None:
+        """Record the state of the session, warning if it cannot follow the previous one.
+
+        Args:
+            state: State of the session, as reported by the server.
+        """
+        new_state = SessionState.parse(state)
+        if new_state is None:
+            logger.debug("Session %s has an unknown state %s.", self._session_id, state)
+            return
+        if self._state is not None and not self._state.can_reach(new_state):
+            logger.warning(
+                "Session %s unexpectedly changed from state %s to %s.",
+                self._session_id,
+                self._state.value,
+                new_state.value,
+            )
+        self._state = new_state
+
     def details(self) -> Optional[Dict[str, Any]]:
         """Return session details.
 
@@ -294,10 +569,22 @@ class Session:
             * ``mode``: Execution mode of the session.
             * ``usage_time``: The usage time, in seconds, of this Session or Batch.
               Usage is defined as the time a quantum system is committed to complete a job.
+            * ``owner``: ID of the user who created the session.
+            * ``elapsed_time``: Time, in seconds, since the session was started, or
+              until it was closed.
+            * ``remaining_ttl``: Time, in seconds, until the session reaches its maximum
+              time, or 0 if it is closed.
+
+            The elapsed and remaining times are computed with the client clock, adjusted
+            for its skew from the server clock.
         """
         if self._session_id and isinstance(self._service, QiskitRuntimeService):
             response = self._service._api_client.session_details(self._session_id)
             if response:
+                self._record_state(response.get("state"))
+                elapsed_time, remaining_ttl = self._session_times(
+                    response, self._service._api_client.server_time()
+                )
                 return {
                     "id": response.get("id"),
                     "backend_name": response.get("backend_name"),
@@ -313,9 +600,38 @@ class Session:
                     "activated_at": response.get("activated_at"),
                     "mode": response.get("mode"),
                     "usage_time": response.get("elapsed_time"),
+                    "owner": response.get("user_id"),
+                    "elapsed_time": elapsed_time,
+                    "remaining_ttl": remaining_ttl,
                 }
         return None
 
+    @staticmethod
+    def _session_times(
+        response: Dict[str, Any], now: datetime
+    ) -> Tuple[Optional[float], Optional[float]]:
+        """Return the elapsed time and remaining time to live of a session, in seconds.
+
+        Args:
+            response: Session details returned by the server.
+            now: Current time, according to the server clock.
+
+        Returns:
+            The elapsed time, or ``None`` if the session has not started, and the
+            remaining time to live, or ``None`` if it cannot be computed.
+        """
+        started_at = str_to_utc(response.get("started_at"))
+        if started_at is None:
+            return None, None
+        closed_at = str_to_utc(response.get("closed_at"))
+        elapsed_time = max(((closed_at or now) - started_at).total_seconds(), 0)
+        if closed_at is not None:
+            return elapsed_time, 0
+        max_ttl = response.get("max_ttl")
+        if max_ttl is None:
+            return elapsed_time, None
+        return elapsed_time, max(max_ttl - elapsed_time, 0)
+
     @property
     def session_id(self) -> Optional[str]:
         """Return the session ID.
@@ -369,10 +685,9 @@ class Session:
         response = service._api_client.session_details(session_id)
         backend = response.get("backend_name")
         mode = response.get("mode")
-        class_name = "dedicated" if cls.__name__.lower() == "session" else cls.__name__.lower()
-        if mode != class_name:
+        if mode != cls._mode:
             raise IBMInputValueError(
-                f"Input ID {session_id} has execution mode {mode} instead of {class_name}."
+                f"Input ID {session_id} has execution mode {mode} instead of {cls._mode}."
             )
 
         session = cls(service, backend)
@@ -17,6 +17,7 @@ from .converters import (
     local_to_utc,
     seconds_to_duration,
     duration_difference,
+    duration_to_seconds,
 )
 from .utils import (
     to_python_identifier,
@@ -31,7 +32,9 @@ from .validations import (
     validate_no_dd_with_dynamic_circuits,
     validate_isa_circuits,
     validate_job_tags,
+    validate_options_profile,
 )
 
 from .json import RuntimeEncoder, RuntimeDecoder, to_base64_string
+from .payload_cache import PayloadCache, enable_payload_cache, disable_payload_cache
 from . import pubsub
@@ -24,7 +24,7 @@ from qiskit.providers.models import (
     QasmBackendConfiguration,
 )
 
-from .converters import utc_to_local_all
+from .converters import utc_to_local_all, normalize_duration_unit
 
 logger = logging.getLogger(__name__)
 
@@ -89,12 +89,24 @@ def defaults_from_server_data(defaults: Dict) -> PulseDefaults:
 def properties_from_server_data(properties: Dict) -> BackendProperties:
     """Decode backend properties.
 
+    The raw ``value`` and ``unit`` of each entry are kept as reported by the server,
+    except that microseconds are always spelled ``us``. The accessors of the returned
+    ``BackendProperties``, such as ``t1()``, ``t2()`` and ``gate_length()``, return
+    durations normalized to seconds.
+
     Args:
         properties: Raw properties data.
 
     Returns:
         A ``BackendProperties`` instance.
     """
+    for qubit in properties.get("qubits", []):
+        for nduv in qubit:
+            _normalize_nduv_unit(nduv)
+    for gate in properties.get("gates", []):
+        for param in gate.get("parameters", []):
+            _normalize_nduv_unit(param)
+
     if isinstance(properties["last_update_date"], str):
         properties["last_update_date"] = dateutil.parser.isoparse(properties["last_update_date"])
         for qubit in properties["qubits"]:
@@ -110,6 +122,16 @@ def properties_from_server_data(properties: Dict) -> BackendProperties:
     return BackendProperties.from_dict(properties)
 
 
+def _normalize_nduv_unit(nduv: Dict) -> None:
+    """Normalize the spelling of the unit of a property entry.
+
+    Args:
+        nduv: A ``Nduv`` in dictionary format.
+    """
+    if "unit" in nduv:
+        nduv["unit"] = normalize_duration_unit(nduv["unit"])
+
+
 def _decode_backend_configuration(config: Dict) -> None:
     """Decode backend configuration.
 
@@ -0,0 +1,93 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Benchmark figures reported for backends."""
+
+from dataclasses import dataclass
+from typing import Any, Dict, Iterable, Optional
+
+from qiskit.providers.models import BackendProperties
+
+EPLG_CHAIN_LENGTH = 100
+"""Number of qubits of the chain whose layer fidelity is used to compute the EPLG."""
+
+
+@dataclass
+class BackendBenchmarks:
+    """Benchmark figures of a backend.
+
+    Figures that the backend does not report are set to ``None``.
+    """
+
+    backend_name: str
+    quantum_volume: Optional[int] = None
+    """Quantum volume."""
+    clops_h: Optional[int] = None
+    """Circuit layer operations per second, measured with hardware-aware circuits."""
+    clops_v: Optional[int] = None
+    """Circuit layer operations per second, measured with virtual circuits."""
+    eplg: Optional[float] = None
+    """Error per layered gate, over a chain of 100 qubits."""
+
+    @classmethod
+    def from_backend(cls, backend: Any) -> "BackendBenchmarks":
+        """Read the benchmark figures of a backend.
+
+        The quantum volume and CLOPS are read from the backend configuration. The EPLG is
+        computed from the layer fidelity of a 100-qubit chain, ``lf_100``, reported in the
+        general backend properties.
+
+        Args:
+            backend: Backend to read the benchmark figures of.
+
+        Returns:
+            The benchmark figures.
+        """
+        configuration = backend.configuration()
+        properties = backend.properties()
+        return cls(
+            backend_name=backend.name,
+            quantum_volume=_to_int(getattr(configuration, "quantum_volume", None)),
+            clops_h=_to_int(getattr(configuration, "clops_h", None)),
+            clops_v=_to_int(getattr(configuration, "clops_v", None)),
+            eplg=_eplg(properties) if properties is not None else None,
+        )
+
+
+def _to_int(value: Any) -> Optional[int]:
+    """Convert a reported figure to an integer, or ``None`` if it is not a number."""
+    try:
+        return int(value)
+    except (TypeError, ValueError):
+        # Missing figures are sometimes reported as the string "None".
+        return None
+
+
+def _eplg(properties: BackendProperties) -> Optional[float]:
+    """Compute the error per layered gate from the layer fidelity of the longest chain."""
+    for item in properties.general:
+        if item.name == f"lf_{EPLG_CHAIN_LENGTH}" and item.value:
+            # A chain of n qubits has n - 1 two-qubit gates per layer.
+            return 1 - item.value ** (1 / (EPLG_CHAIN_LENGTH - 1))
+    return None
+
+
+def fleet_benchmarks(backends: Iterable[Any]) -> Dict[str, BackendBenchmarks]:
+    """Read the benchmark figures of several backends.
+
+    Args:
+        backends: Backends to read the benchmark figures of.
+
+    Returns:
+        The benchmark figures, keyed by backend name.
+    """
+    return {backend.name: BackendBenchmarks.from_backend(backend) for backend in backends}
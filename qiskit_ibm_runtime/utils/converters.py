@@ -20,6 +20,17 @@ from typing import Union, Tuple, Any, Optional
 from dateutil import tz, parser
 from qiskit_ibm_runtime.exceptions import IBMInputValueError
 
+DURATION_UNIT_SCALES = {
+    "s": 1.0,
+    "ms": 1e-3,
+    "us": 1e-6,
+    "µs": 1e-6,  # micro sign
+    "μs": 1e-6,  # greek small letter mu
+    "ns": 1e-9,
+    "ps": 1e-12,
+}
+"""Scale factors from the time units used in backend properties to seconds."""
+
 
 def utc_to_local(utc_dt: Union[datetime, str]) -> datetime:
     """Convert a UTC ``datetime`` object or string to a local timezone ``datetime``.
@@ -222,3 +233,39 @@ def hms_to_seconds(hms: str, msg_prefix: str = "") -> int:
         raise IBMInputValueError(f"{msg_prefix} Invalid input: {parsed_time}")
 
     return total_seconds
+
+
+def normalize_duration_unit(unit: str) -> str:
+    """Return the ASCII spelling of a time unit used in backend properties.
+
+    Backend properties may report microseconds as ``µs`` or ``μs``; both are
+    returned as ``us`` so they can be understood by ``qiskit``. Other units are
+    returned unchanged.
+
+    Args:
+        unit: Unit as reported by the server.
+
+    Returns:
+        The normalized unit.
+    """
+    if unit in ("µs", "μs"):
+        return "us"
+    return unit
+
+
+def duration_to_seconds(value: float, unit: str) -> float:
+    """Convert a duration reported in backend properties to seconds.
+
+    Args:
+        value: Duration in ``unit``.
+        unit: Time unit of ``value``, for example ``ns`` or ``us``.
+
+    Returns:
+        The duration in seconds.
+
+    Raises:
+        IBMInputValueError: If the unit is not a known time unit.
+    """
+    if unit not in DURATION_UNIT_SCALES:
+        raise IBMInputValueError(f"Unknown time unit: {unit}")
+    return value * DURATION_UNIT_SCALES[unit]
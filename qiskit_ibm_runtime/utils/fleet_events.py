@@ -0,0 +1,205 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Events of a fleet of backends, detected by polling their status and properties."""
+
+import asyncio
+import logging
+import threading
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from typing import Any, AsyncIterator, ClassVar, Dict, Iterator, List, Optional, Sequence, Tuple
+
+from ..api.exceptions import RequestsApiError
+
+logger = logging.getLogger(__name__)
+
+WENT_DOWN = "went_down"
+CAME_UP = "came_up"
+CALIBRATION_UPDATED = "calibration_updated"
+QUEUE_ABOVE_THRESHOLD = "queue_above_threshold"
+QUEUE_BELOW_THRESHOLD = "queue_below_threshold"
+
+
+@dataclass(frozen=True)
+class FleetEvent:
+    """Change in the state of a backend."""
+
+    WENT_DOWN: ClassVar[str] = WENT_DOWN
+    CAME_UP: ClassVar[str] = CAME_UP
+    CALIBRATION_UPDATED: ClassVar[str] = CALIBRATION_UPDATED
+    QUEUE_ABOVE_THRESHOLD: ClassVar[str] = QUEUE_ABOVE_THRESHOLD
+    QUEUE_BELOW_THRESHOLD: ClassVar[str] = QUEUE_BELOW_THRESHOLD
+
+    backend_name: str
+    event_type: str
+    """One of ``"went_down"``, ``"came_up"``, ``"calibration_updated"``,
+    ``"queue_above_threshold"`` and ``"queue_below_threshold"``."""
+    timestamp: datetime
+    """Time, in UTC, at which the change was detected."""
+    details: Dict[str, Any] = field(default_factory=dict)
+    """Details of the change: the status message for status changes, the date of the
+    new calibration, or the number of pending jobs."""
+
+
+@dataclass
+class _BackendSnapshot:
+    """State of a backend at a poll."""
+
+    operational: bool
+    status_msg: str
+    pending_jobs: int
+    last_update_date: Optional[datetime]
+
+
+class FleetMonitor:
+    """Watch backends for outages, new calibrations and long queues.
+
+    The backends are polled periodically, and each change since the previous poll is
+    reported as a :class:`FleetEvent`. The first poll only records the initial state of
+    the backends.
+
+    The events can be iterated over in a loop or, from asynchronous code, with
+    ``async for``, which polls the backends in a worker thread. Either way, the
+    events are detected by polling: the API does not push backend changes.
+
+    Example::
+
+        monitor = FleetMonitor(service.backends(), interval=300, queue_threshold=100)
+        for event in monitor:
+            if event.event_type == FleetEvent.WENT_DOWN:
+                alert(event.backend_name, event.details["status_msg"])
+    """
+
+    def __init__(
+        self,
+        backends: Sequence[Any],
+        interval: float = 60,
+        queue_threshold: Optional[int] = None,
+    ):
+        """FleetMonitor constructor.
+
+        Args:
+            backends: Backends to watch.
+            interval: Number of seconds between polls.
+            queue_threshold: Number of pending jobs above which a backend is reported as
+                having a long queue. If ``None``, queue lengths are not watched.
+
+        Raises:
+            ValueError: If ``interval`` is not positive or ``queue_threshold`` is negative.
+        """
+        if interval <= 0:
+            raise ValueError("The polling interval must be positive.")
+        if queue_threshold is not None and queue_threshold < 0:
+            raise ValueError("The queue threshold must not be negative.")
+        self._backends = list(backends)
+        self._interval = interval
+        self._queue_threshold = queue_threshold
+        self._snapshots: Dict[str, _BackendSnapshot] = {}
+        self._stop_event = threading.Event()
+        self._async_stop: Optional[Tuple[asyncio.AbstractEventLoop, asyncio.Event]] = None
+
+    def poll(self) -> List[FleetEvent]:
+        """Poll the backends once.
+
+        Returns:
+            The changes since the previous poll, in the order of the backends.
+        """
+        events = []
+        for backend in self._backends:
+            try:
+                snapshot = self._snapshot(backend)
+            except RequestsApiError as ex:
+                logger.warning("Unable to retrieve the state of %s: %s", backend.name, ex)
+                continue
+            previous = self._snapshots.get(backend.name)
+            self._snapshots[backend.name] = snapshot
+            if previous is not None:
+                events.extend(self._compare(backend.name, previous, snapshot))
+        return events
+
+    def stop(self) -> None:
+        """Stop iterating over the events, after the current poll."""
+        self._stop_event.set()
+        async_stop = self._async_stop
+        if async_stop is not None:
+            loop, stopped = async_stop
+            try:
+                loop.call_soon_threadsafe(stopped.set)
+            except RuntimeError:
+                # The event loop is already closed.
+                pass
+
+    def _snapshot(self, backend: Any) -> _BackendSnapshot:
+        """Return the current state of a backend."""
+        status = backend.status()
+        properties = backend.properties(refresh=True)
+        return _BackendSnapshot(
+            operational=status.operational,
+            status_msg=status.status_msg,
+            pending_jobs=status.pending_jobs,
+            last_update_date=properties.last_update_date if properties else None,
+        )
+
+    def _compare(
+        self, backend_name: str, previous: _BackendSnapshot, current: _BackendSnapshot
+    ) -> List[FleetEvent]:
+        """Return the events between two states of a backend."""
+        now = datetime.now(timezone.utc)
+        events = []
+        if previous.operational != current.operational:
+            event_type = CAME_UP if current.operational else WENT_DOWN
+            events.append(
+                FleetEvent(backend_name, event_type, now, {"status_msg": current.status_msg})
+            )
+        if current.last_update_date != previous.last_update_date and current.last_update_date:
+            events.append(
+                FleetEvent(
+                    backend_name,
+                    CALIBRATION_UPDATED,
+                    now,
+                    {"last_update_date": current.last_update_date},
+                )
+            )
+        if self._queue_threshold is not None:
+            was_above = previous.pending_jobs > self._queue_threshold
+            is_above = current.pending_jobs > self._queue_threshold
+            if was_above != is_above:
+                event_type = QUEUE_ABOVE_THRESHOLD if is_above else QUEUE_BELOW_THRESHOLD
+                events.append(
+                    FleetEvent(
+                        backend_name, event_type, now, {"pending_jobs": current.pending_jobs}
+                    )
+                )
+        return events
+
+    def __iter__(self) -> Iterator[FleetEvent]:
+        self._stop_event.clear()
+        while not self._stop_event.is_set():
+            yield from self.poll()
+            self._stop_event.wait(self._interval)
+
+    async def __aiter__(self) -> AsyncIterator[FleetEvent]:
+        self._stop_event.clear()
+        loop = asyncio.get_running_loop()
+        stopped = asyncio.Event()
+        self._async_stop = (loop, stopped)
+        try:
+            while not self._stop_event.is_set():
+                for event in await loop.run_in_executor(None, self.poll):
+                    yield event
+                try:
+                    await asyncio.wait_for(stopped.wait(), self._interval)
+                except asyncio.TimeoutError:
+                    pass
+        finally:
+            self._async_stop = None
@@ -0,0 +1,264 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Rows, bridges and unit cells of heavy-hex coupling maps."""
+
+from dataclasses import dataclass
+from typing import Any, Dict, Iterable, List, Optional, Set, Tuple
+
+from ..exceptions import IBMInputValueError
+
+CELL_SIZE = 12
+"""Number of qubits in the ring of a heavy-hex unit cell."""
+
+
+@dataclass(frozen=True)
+class HeavyHexCell:
+    """Unit cell of a heavy-hex lattice, a ring of 12 qubits spanning two rows."""
+
+    row: int
+    """Index of the upper of the two rows spanned by the cell."""
+    column: int
+    """Index of the cell among the cells spanning the same rows, from left to right."""
+    qubits: Tuple[int, ...]
+    """Qubits of the ring, starting with the leftmost qubit of the upper row and going
+    along that row."""
+    bridges: Tuple[int, int]
+    """Left and right bridge qubits, connecting the two rows."""
+
+
+class HeavyHexTopology:
+    """Heavy-hex structure of a coupling map.
+
+    The qubits of a heavy-hex lattice are arranged in rows, which are chains of qubits,
+    connected by bridge qubits. Each unit cell is a ring of 12 qubits: five qubits of
+    each of two consecutive rows and the two bridges connecting them. The rows are told
+    apart from the bridges using the numbering of the Eagle and Heron processors, where
+    the qubits of a row are numbered consecutively.
+
+    Example::
+
+        topology = HeavyHexTopology.from_backend(backend)
+        cell = topology.cell(row=2, column=1)
+        print(cell.qubits, topology.position(cell.qubits[0]))
+    """
+
+    def __init__(self, edges: Iterable[Tuple[int, int]]) -> None:
+        """HeavyHexTopology constructor.
+
+        Args:
+            edges: Edges of the coupling map. Their direction is ignored.
+
+        Raises:
+            IBMInputValueError: If the coupling map is not a heavy-hex lattice.
+        """
+        self._neighbors: Dict[int, Set[int]] = {}
+        for qubit1, qubit2 in edges:
+            if qubit1 != qubit2:
+                self._neighbors.setdefault(qubit1, set()).add(qubit2)
+                self._neighbors.setdefault(qubit2, set()).add(qubit1)
+        if any(len(neighbors) > 3 for neighbors in self._neighbors.values()):
+            raise IBMInputValueError(
+                "The coupling map is not a heavy-hex lattice: a qubit has more than 3 neighbors."
+            )
+        rings = self._find_rings()
+        if not rings:
+            raise IBMInputValueError(
+                "The coupling map is not a heavy-hex lattice: it has no 12-qubit unit cell."
+            )
+
+        # The bridges are the qubits with at most 2 neighbors that are not numbered
+        # consecutively with any of them.
+        bridges = {
+            qubit
+            for qubit, neighbors in self._neighbors.items()
+            if len(neighbors) <= 2 and all(abs(qubit - neighbor) != 1 for neighbor in neighbors)
+        }
+        self.rows: List[List[int]] = self._find_rows(bridges)
+        """Qubits of each row, from left to right. Rows are ordered from top to bottom."""
+        self._positions = {
+            qubit: (row, column)
+            for row, qubits in enumerate(self.rows)
+            for column, qubit in enumerate(qubits)
+        }
+
+        self.bridges: Dict[int, Tuple[int, ...]] = {}
+        """Rows connected by each bridge qubit, upper row first. The bridges at the top and
+        bottom edges of the lattice are connected to a single row."""
+        for bridge in sorted(bridges):
+            rows = sorted({self._positions[qubit][0] for qubit in self._neighbors[bridge]})
+            if len(rows) != len(self._neighbors[bridge]) or rows[-1] > rows[0] + 1:
+                raise IBMInputValueError(
+                    f"The coupling map is not a heavy-hex lattice: bridge qubit {bridge} "
+                    "does not connect consecutive rows."
+                )
+            self.bridges[bridge] = tuple(rows)
+
+        self.cells: List[HeavyHexCell] = self._build_cells(rings)
+        """Unit cells, ordered by row and column."""
+
+    @classmethod
+    def from_backend(cls, backend: Any) -> "HeavyHexTopology":
+        """Read the heavy-hex structure of the coupling map of a backend.
+
+        Args:
+            backend: Backend with a heavy-hex coupling map.
+
+        Returns:
+            The heavy-hex structure.
+        """
+        return cls(backend.coupling_map.get_edges())
+
+    def row_of(self, qubit: int) -> Optional[int]:
+        """Return the row of a qubit, or ``None`` if it is a bridge qubit.
+
+        Args:
+            qubit: Qubit.
+
+        Returns:
+            Index of the row of the qubit.
+
+        Raises:
+            IBMInputValueError: If the qubit is not in the coupling map.
+        """
+        if qubit in self.bridges:
+            return None
+        return self.position(qubit)[0]
+
+    def position(self, qubit: int) -> Tuple[int, int]:
+        """Return the row of a qubit and its index in the row.
+
+        Args:
+            qubit: Qubit of a row.
+
+        Returns:
+            A tuple with the index of the row and the index of the qubit in the row.
+
+        Raises:
+            IBMInputValueError: If the qubit is a bridge qubit or is not in the coupling map.
+        """
+        if qubit not in self._positions:
+            if qubit in self.bridges:
+                raise IBMInputValueError(f"Qubit {qubit} is a bridge qubit, not in a row.")
+            raise IBMInputValueError(f"Qubit {qubit} is not in the coupling map.")
+        return self._positions[qubit]
+
+    def cell(self, row: int, column: int) -> HeavyHexCell:
+        """Return a unit cell.
+
+        Args:
+            row: Index of the upper of the two rows spanned by the cell.
+            column: Index of the cell among the cells spanning the same rows.
+
+        Returns:
+            The unit cell.
+
+        Raises:
+            IBMInputValueError: If there is no such cell.
+        """
+        for cell in self.cells:
+            if (cell.row, cell.column) == (row, column):
+                return cell
+        raise IBMInputValueError(f"There is no unit cell at row {row} and column {column}.")
+
+    def cells_of(self, qubit: int) -> List[HeavyHexCell]:
+        """Return the unit cells that contain a qubit.
+
+        Args:
+            qubit: Qubit.
+
+        Returns:
+            The unit cells, ordered by row and column.
+        """
+        return [cell for cell in self.cells if qubit in cell.qubits]
+
+    def _find_rings(self) -> List[Tuple[int, ...]]:
+        """Return the 12-qubit rings of the coupling map, starting with their lowest qubit."""
+        rings = []
+        for start in sorted(self._neighbors):
+            paths = [[start]]
+            while paths:
+                path = paths.pop()
+                if len(path) == CELL_SIZE:
+                    # Each ring is found in both directions; keep one of them.
+                    if start in self._neighbors[path[-1]] and path[1] < path[-1]:
+                        rings.append(tuple(path))
+                    continue
+                for neighbor in self._neighbors[path[-1]]:
+                    if neighbor > start and neighbor not in path:
+                        paths.append(path + [neighbor])
+        return rings
+
+    def _find_rows(self, bridges: Set[int]) -> List[List[int]]:
+        """Return the rows, which are the chains of qubits left when the bridges are removed."""
+        rows = []
+        visited: Set[int] = set()
+        for qubit in sorted(self._neighbors):
+            if qubit in bridges or qubit in visited:
+                continue
+            # Collect the chain of the qubit, then walk it from its lowest end.
+            chain = {qubit}
+            pending = [qubit]
+            while pending:
+                for neighbor in self._neighbors[pending.pop()] - bridges:
+                    if neighbor not in chain:
+                        chain.add(neighbor)
+                        pending.append(neighbor)
+            row_neighbors = {member: self._neighbors[member] & chain for member in chain}
+            ends = sorted(member for member in chain if len(row_neighbors[member]) < 2)
+            num_edges = sum(len(neighbors) for neighbors in row_neighbors.values()) // 2
+            is_chain = all(len(neighbors) <= 2 for neighbors in row_neighbors.values())
+            if not is_chain or num_edges != len(chain) - 1:
+                raise IBMInputValueError(
+                    f"The coupling map is not a heavy-hex lattice: the row of qubit {qubit} "
+                    "is not a chain. The qubits of each row should be numbered consecutively."
+                )
+            row = [ends[0]]
+            while len(row) < len(chain):
+                row.append(next(iter(row_neighbors[row[-1]] - set(row[-2:]))))
+            visited |= chain
+            if rows and rows[-1][-1] == row[0] - 1:
+                # The chains are parts of the same row, split by a missing coupler.
+                rows[-1].extend(row)
+            else:
+                rows.append(row)
+        return rows
+
+    def _build_cells(self, rings: List[Tuple[int, ...]]) -> List[HeavyHexCell]:
+        """Return the unit cells of the rings, ordered by row and column."""
+        oriented = []
+        for ring in rings:
+            rows = {self._positions[qubit][0] for qubit in ring if qubit in self._positions}
+            row = min(rows)
+            first = min(
+                (qubit for qubit in ring if self._positions.get(qubit, (None,))[0] == row),
+                key=lambda qubit: self._positions[qubit][1],
+            )
+            # Rotate the ring to start with its leftmost upper qubit and go along the row.
+            index = ring.index(first)
+            qubits = ring[index:] + ring[:index]
+            if qubits[1] in self.bridges:
+                qubits = qubits[:1] + qubits[:0:-1]
+            bridges = [qubit for qubit in qubits if qubit in self.bridges]
+            if len(rows) != 2 or len(bridges) != 2:
+                raise IBMInputValueError(
+                    f"The coupling map is not a heavy-hex lattice: the ring of qubits {ring} "
+                    "does not span two rows connected by two bridges."
+                )
+            # Going along the upper row, the right bridge comes first.
+            oriented.append((row, self._positions[first][1], qubits, (bridges[1], bridges[0])))
+        oriented.sort(key=lambda cell: cell[:2])
+        cells = []
+        for row, _, qubits, bridges in oriented:
+            column = sum(1 for cell in cells if cell.row == row)
+            cells.append(HeavyHexCell(row=row, column=column, qubits=qubits, bridges=bridges))
+        return cells
@@ -12,7 +12,8 @@
 
 """Hub/group/project utility functions."""
 
-from typing import Tuple
+from dataclasses import dataclass, field
+from typing import List, Tuple
 from ..exceptions import IBMInputValueError
 
 
@@ -40,3 +41,37 @@ def from_instance_format(instance: str) -> Tuple[str, str, str]:
 def to_instance_format(hub: str, group: str, project: str) -> str:
     """Convert input to hub/group/project format."""
     return f"{hub}/{group}/{project}"
+
+
+@dataclass
+class InstanceGroup:
+    """Group of IBM Quantum instances, the projects of a hub and group."""
+
+    hub: str
+    group: str
+    instances: List[str] = field(default_factory=list)
+    """Instances of the group, in hub/group/project format."""
+
+    @property
+    def name(self) -> str:
+        """Name of the group, in hub/group format."""
+        return f"{self.hub}/{self.group}"
+
+
+def in_instance_group(instance: str, group: str) -> bool:
+    """Return whether an instance belongs to a hub, or to a hub and group.
+
+    Args:
+        instance: Service instance in hub/group/project format.
+        group: Hub, or hub and group in hub/group format.
+
+    Returns:
+        Whether the instance belongs to the group.
+
+    Raises:
+        IBMInputValueError: If the group is not in the correct format.
+    """
+    parts = group.split("/")
+    if len(parts) > 2 or not all(parts):
+        raise IBMInputValueError(f"Input group value {group} is not in the hub/group format.")
+    return list(from_instance_format(instance)[: len(parts)]) == parts
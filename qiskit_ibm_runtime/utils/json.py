@@ -24,6 +24,7 @@ import re
 import warnings
 import zlib
 from datetime import date
+from functools import partial
 
 from typing import Any, Callable, Dict, List, Union, get_args
 
@@ -72,6 +73,8 @@ from qiskit.primitives.containers import (
 )
 from qiskit_ibm_runtime.options.zne_options import ExtrapolatorType
 
+from .payload_cache import get_payload_cache
+
 _TERRA_VERSION = tuple(
     int(x) for x in re.match(r"\d+\.\d+\.\d", _terra_version_string).group(0).split(".")[:3]
 )
@@ -103,14 +106,39 @@ def _serialize_and_encode(
     Returns:
         String representation.
     """
+    serialized_data = _serialize(data, serializer, **kwargs)
+    if compress:
+        return _compress_and_encode(serialized_data)
+    return base64.standard_b64encode(serialized_data).decode("utf-8")
+
+
+def _serialize(data: Any, serializer: Callable, **kwargs: Any) -> bytes:
+    """Serialize the input data.
+
+    Args:
+        data: Data to be serialized.
+        serializer: Function used to serialize data.
+        kwargs: Keyword arguments to pass to the serializer.
+
+    Returns:
+        Serialized data.
+    """
     with io.BytesIO() as buff:
         serializer(buff, data, **kwargs)
         buff.seek(0)
-        serialized_data = buff.read()
+        return buff.read()
 
-    if compress:
-        serialized_data = zlib.compress(serialized_data)
-    return base64.standard_b64encode(serialized_data).decode("utf-8")
+
+def _compress_and_encode(serialized_data: bytes) -> str:
+    """Compress serialized data and return the encoded string.
+
+    Args:
+        serialized_data: Serialized data.
+
+    Returns:
+        String representation.
+    """
+    return base64.standard_b64encode(zlib.compress(serialized_data)).decode("utf-8")
 
 
 def _decode_and_deserialize(data: str, deserializer: Callable, decompress: bool = True) -> Any:
@@ -203,6 +231,15 @@ def _cast_strings_keys_to_int(obj: Dict) -> Dict:
 class RuntimeEncoder(json.JSONEncoder):
     """JSON Encoder used by runtime service."""
 
+    def __init__(self, *args: Any, **kwargs: Any) -> None:
+        super().__init__(*args, **kwargs)
+        self.num_circuits = 0
+        """Number of circuits encoded."""
+        self.circuit_bytes = 0
+        """Size of the encoded circuits before compression, in bytes."""
+        self.encoded_circuit_bytes = 0
+        """Size of the encoded circuits after compression, in bytes."""
+
     def default(self, obj: Any) -> Any:  # pylint: disable=arguments-differ
         if isinstance(obj, date):
             return {"__type__": "datetime", "__value__": obj.isoformat()}
@@ -225,12 +262,22 @@ class RuntimeEncoder(json.JSONEncoder):
             kwargs: Dict[str, object] = {"use_symengine": bool(optionals.HAS_SYMENGINE)}
             if _TERRA_VERSION[0] >= 1:
                 kwargs["version"] = 11
-            value = _serialize_and_encode(
-                data=obj,
+            serialize_circuit = partial(
+                _serialize,
                 serializer=lambda buff, data: dump(
                     data, buff, RuntimeEncoder, **kwargs
                 ),  # type: ignore[no-untyped-call]
             )
+            payload_cache = get_payload_cache()
+            if payload_cache is not None:
+                serialized = payload_cache.serialize(obj, serialize_circuit)
+                value = payload_cache.encode(serialized, _compress_and_encode)
+            else:
+                serialized = serialize_circuit(obj)
+                value = _compress_and_encode(serialized)
+            self.num_circuits += 1
+            self.circuit_bytes += len(serialized)
+            self.encoded_circuit_bytes += len(value)
             return {"__type__": "QuantumCircuit", "__value__": value}
         if isinstance(obj, Parameter):
             value = _serialize_and_encode(
@@ -0,0 +1,156 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Typed views of the error mitigation metadata returned by the primitives."""
+
+from dataclasses import dataclass, field, fields
+from typing import Any, Dict, List, Optional, Type, TypeVar
+
+from qiskit.primitives.containers import PrimitiveResult
+
+T = TypeVar("T", bound="_MetadataBase")
+
+
+@dataclass
+class _MetadataBase:
+    """Base class for metadata sections."""
+
+    raw: Dict[str, Any] = field(default_factory=dict, repr=False)
+    """The metadata section as returned by the server, including unknown fields."""
+
+    @classmethod
+    def from_dict(cls: Type[T], data: Optional[Dict[str, Any]]) -> Optional[T]:
+        """Create the metadata section from the dictionary returned by the server.
+
+        Args:
+            data: Metadata section returned by the server.
+
+        Returns:
+            The metadata section, or ``None`` if ``data`` is empty.
+        """
+        if not data:
+            return None
+        known = {item.name for item in fields(cls)} - {"raw"}
+        return cls(raw=dict(data), **{key: value for key, value in data.items() if key in known})
+
+
+@dataclass
+class TwirlingMetadata(_MetadataBase):
+    """Pauli twirling that was applied to the circuits."""
+
+    enable_gates: Optional[bool] = None
+    enable_measure: Optional[bool] = None
+    num_randomizations: Optional[Any] = None
+    shots_per_randomization: Optional[Any] = None
+    interleave_randomizations: Optional[bool] = None
+    strategy: Optional[str] = None
+
+
+@dataclass
+class DynamicalDecouplingMetadata(_MetadataBase):
+    """Dynamical decoupling that was applied to the circuits."""
+
+    enable: Optional[bool] = None
+    sequence_type: Optional[str] = None
+    extra_slack_distribution: Optional[str] = None
+    scheduling_method: Optional[str] = None
+
+
+@dataclass
+class ResilienceMetadata(_MetadataBase):
+    """Error mitigation methods that ran for the job."""
+
+    measure_mitigation: Optional[bool] = None
+    """Whether twirled readout error extinction (TREX) was applied."""
+    zne_mitigation: Optional[bool] = None
+    """Whether zero noise extrapolation (ZNE) was applied."""
+    pec_mitigation: Optional[bool] = None
+    """Whether probabilistic error cancellation (PEC) was applied."""
+
+
+@dataclass
+class ZneMetadata(_MetadataBase):
+    """Details of the zero noise extrapolation of a pub."""
+
+    noise_factors: Optional[List[float]] = None
+    extrapolator: Optional[Any] = None
+    extrapolated_noise_factors: Optional[List[float]] = None
+
+
+@dataclass
+class LayerNoiseMetadata(_MetadataBase):
+    """Layer noise learned for the probabilistic error amplification or cancellation of a pub."""
+
+    noise_overhead: Optional[float] = None
+    total_mitigated_layers: Optional[int] = None
+    unique_mitigated_layers: Optional[int] = None
+    unique_mitigated_layers_noise_overhead: Optional[List[float]] = None
+
+
+@dataclass
+class PubMitigationMetadata:
+    """Error mitigation metadata of a single pub."""
+
+    zne: Optional[ZneMetadata] = None
+    layer_noise: Optional[LayerNoiseMetadata] = None
+    raw: Dict[str, Any] = field(default_factory=dict, repr=False)
+    """The ``resilience`` metadata of the pub as returned by the server."""
+
+
+@dataclass
+class MitigationMetadata:
+    """Error mitigation metadata of a primitive result.
+
+    Example::
+
+        result = job.result()
+        metadata = MitigationMetadata.from_result(result)
+        if metadata.resilience and metadata.resilience.zne_mitigation:
+            print(metadata.pubs[0].zne.extrapolator)
+    """
+
+    twirling: Optional[TwirlingMetadata] = None
+    dynamical_decoupling: Optional[DynamicalDecouplingMetadata] = None
+    resilience: Optional[ResilienceMetadata] = None
+    pubs: List[PubMitigationMetadata] = field(default_factory=list)
+
+    @classmethod
+    def from_result(cls, result: PrimitiveResult) -> "MitigationMetadata":
+        """Parse the error mitigation metadata of a primitive result.
+
+        Sections that are missing from the result are set to ``None``.
+
+        Args:
+            result: Result of a V2 primitive job.
+
+        Returns:
+            The error mitigation metadata.
+        """
+        metadata = result.metadata or {}
+        pubs = []
+        for pub_result in result:
+            resilience = (pub_result.metadata or {}).get("resilience") or {}
+            pubs.append(
+                PubMitigationMetadata(
+                    zne=ZneMetadata.from_dict(resilience.get("zne")),
+                    layer_noise=LayerNoiseMetadata.from_dict(resilience.get("layer_noise")),
+                    raw=dict(resilience),
+                )
+            )
+        return cls(
+            twirling=TwirlingMetadata.from_dict(metadata.get("twirling")),
+            dynamical_decoupling=DynamicalDecouplingMetadata.from_dict(
+                metadata.get("dynamical_decoupling")
+            ),
+            resilience=ResilienceMetadata.from_dict(metadata.get("resilience")),
+            pubs=pubs,
+        )
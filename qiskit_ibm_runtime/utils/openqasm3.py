@@ -0,0 +1,79 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Submission of OpenQASM 3 programs to the primitives."""
+
+from typing import Any, Iterable, List
+
+from qiskit import qasm3
+from qiskit.circuit import QuantumCircuit
+from qiskit.exceptions import MissingOptionalLibraryError
+
+from ..exceptions import IBMInputValueError
+
+MAX_QASM3_PROGRAM_SIZE = 5 * 1024 * 1024
+"""Maximum size, in bytes, of an OpenQASM 3 program."""
+
+
+def qasm3_to_circuit(program: str) -> QuantumCircuit:
+    """Parse an OpenQASM 3 program into a circuit.
+
+    Args:
+        program: OpenQASM 3 program.
+
+    Returns:
+        The circuit described by the program.
+
+    Raises:
+        IBMInputValueError: If the program is empty, too large or invalid.
+        MissingOptionalLibraryError: If ``qiskit-qasm3-import`` is not installed.
+    """
+    if not program.strip():
+        raise IBMInputValueError("The OpenQASM 3 program is empty.")
+    size = len(program.encode("utf-8"))
+    if size > MAX_QASM3_PROGRAM_SIZE:
+        raise IBMInputValueError(
+            f"The OpenQASM 3 program is {size} bytes, which exceeds the maximum "
+            f"of {MAX_QASM3_PROGRAM_SIZE} bytes."
+        )
+    try:
+        return qasm3.loads(program)
+    except MissingOptionalLibraryError:
+        raise
+    except Exception as ex:  # pylint: disable=broad-except
+        # Syntax errors are raised by the parser, and semantic errors by the importer.
+        raise IBMInputValueError(f"Invalid OpenQASM 3 program: {ex}") from None
+
+
+def coerce_qasm3_pubs(pubs: Iterable[Any]) -> List[Any]:
+    """Replace the OpenQASM 3 programs given as pub circuits with circuits.
+
+    A pub can be an OpenQASM 3 program, or a tuple whose first element is an
+    OpenQASM 3 program. Other pubs are returned unchanged.
+
+    Args:
+        pubs: Pub-like objects.
+
+    Returns:
+        The pubs, with circuits instead of OpenQASM 3 programs.
+
+    Raises:
+        IBMInputValueError: If a program is empty, too large or invalid.
+    """
+    coerced = []
+    for pub in pubs:
+        if isinstance(pub, str):
+            pub = qasm3_to_circuit(pub)
+        elif isinstance(pub, (tuple, list)) and pub and isinstance(pub[0], str):
+            pub = (qasm3_to_circuit(pub[0]), *pub[1:])
+        coerced.append(pub)
+    return coerced
@@ -0,0 +1,39 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""List of items retrieved from a paginated endpoint."""
+
+from typing import Iterable, List, Optional, TypeVar
+
+T = TypeVar("T")
+
+
+class PagedList(List[T]):
+    """List of the items retrieved from a paginated endpoint, with the total number of
+    items on the server.
+
+    This allows showing, for example, "50 of 12340 jobs" without retrieving all the items::
+
+        jobs = service.jobs(limit=50)
+        print(f"{len(jobs)} of {jobs.total_count} jobs")
+    """
+
+    def __init__(self, items: Iterable[T] = (), total_count: Optional[int] = None):
+        """PagedList constructor.
+
+        Args:
+            items: Retrieved items.
+            total_count: Number of items matching the filters on the server, regardless
+                of the limit and the starting index of the retrieval. ``None`` if unknown.
+        """
+        super().__init__(items)
+        self.total_count = total_count
@@ -0,0 +1,241 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Content-addressed cache of encoded circuit payloads."""
+
+import hashlib
+import os
+import threading
+import weakref
+from collections import OrderedDict
+from typing import Any, Callable, Optional, Tuple
+
+from qiskit.circuit import QuantumCircuit
+
+DEFAULT_MAX_ENTRIES = 128
+"""Default maximum number of encoded payloads kept in memory."""
+DEFAULT_MAX_DISK_BYTES = 256 * 1024 * 1024
+"""Default maximum total size, in bytes, of the encoded payloads stored on disk."""
+
+
+class PayloadCache:
+    """Cache of encoded circuit payloads, keyed by the hash of the serialized circuits.
+
+    Submitting the same circuits repeatedly, for example in a parameter sweep where only
+    the parameter values change, then reuses the compressed and encoded payload instead
+    of compressing and encoding it again. The QPY serialization of a circuit object is
+    also reused as long as the circuit is not modified, which is checked against a
+    structural fingerprint of its instructions, registers, layout, name, metadata and
+    global phase. The payloads are kept in memory, in least recently used order, and
+    optionally on disk so that they are reused across processes. The least recently used
+    payloads on disk are removed once their total size exceeds ``max_disk_bytes``.
+    """
+
+    def __init__(
+        self,
+        max_entries: int = DEFAULT_MAX_ENTRIES,
+        directory: Optional[str] = None,
+        max_disk_bytes: int = DEFAULT_MAX_DISK_BYTES,
+    ) -> None:
+        """PayloadCache constructor.
+
+        Args:
+            max_entries: Maximum number of encoded payloads kept in memory.
+            directory: Directory to also store the encoded payloads in. It is created
+                if needed. By default, the payloads are only kept in memory.
+            max_disk_bytes: Maximum total size, in bytes, of the payloads stored on disk.
+        """
+        if directory:
+            os.makedirs(directory, exist_ok=True)
+        self._max_entries = max_entries
+        self._directory = directory
+        self._max_disk_bytes = max_disk_bytes
+        self._entries: "OrderedDict[str, str]" = OrderedDict()
+        self._serialized: "OrderedDict[int, Tuple[weakref.ref, Tuple, bytes]]" = OrderedDict()
+        self._lock = threading.Lock()
+        self.hits = 0
+        self.misses = 0
+
+    def serialize(
+        self, circuit: QuantumCircuit, serializer: Callable[[QuantumCircuit], bytes]
+    ) -> bytes:
+        """Return the serialized circuit, serializing it if it changed since the last call.
+
+        Operations modified in place, rather than replaced in the circuit, are not
+        detected.
+
+        Args:
+            circuit: Circuit to serialize.
+            serializer: Function used to serialize the circuit.
+
+        Returns:
+            The serialized circuit.
+        """
+        fingerprint = _circuit_fingerprint(circuit)
+        with self._lock:
+            cached = self._serialized.get(id(circuit))
+            if cached is not None and cached[0]() is circuit:
+                self._serialized.move_to_end(id(circuit))
+            else:
+                cached = None
+        if cached is not None and cached[1] == fingerprint:
+            return cached[2]
+        serialized = serializer(circuit)
+        with self._lock:
+            self._serialized[id(circuit)] = (weakref.ref(circuit), fingerprint, serialized)
+            self._serialized.move_to_end(id(circuit))
+            while len(self._serialized) > self._max_entries:
+                self._serialized.popitem(last=False)
+        return serialized
+
+    def encode(self, serialized: bytes, encoder: Callable[[bytes], str]) -> str:
+        """Return the encoded payload of serialized data, encoding it if it is not cached.
+
+        Args:
+            serialized: Serialized data.
+            encoder: Function used to encode the serialized data.
+
+        Returns:
+            The encoded payload.
+        """
+        key = hashlib.sha256(serialized).hexdigest()
+        with self._lock:
+            if key in self._entries:
+                self._entries.move_to_end(key)
+                self.hits += 1
+                return self._entries[key]
+        encoded = self._load(key)
+        if encoded is None:
+            encoded = encoder(serialized)
+            self._store(key, encoded)
+            with self._lock:
+                self.misses += 1
+        else:
+            with self._lock:
+                self.hits += 1
+        with self._lock:
+            self._entries[key] = encoded
+            while len(self._entries) > self._max_entries:
+                self._entries.popitem(last=False)
+        return encoded
+
+    def clear(self) -> None:
+        """Remove the payloads kept in memory. Payloads stored on disk are kept."""
+        with self._lock:
+            self._entries.clear()
+            self._serialized.clear()
+
+    def _path(self, key: str) -> str:
+        """Return the path of the file storing a payload."""
+        return os.path.join(self._directory, f"{key}.b64")
+
+    def _load(self, key: str) -> Optional[str]:
+        """Load a payload from disk, if it is stored there."""
+        if not self._directory:
+            return None
+        try:
+            with open(self._path(key), encoding="utf-8") as file:
+                encoded = file.read()
+            # Mark the payload as recently used, for the eviction of old payloads.
+            os.utime(self._path(key))
+        except FileNotFoundError:
+            return None
+        return encoded
+
+    def _store(self, key: str, encoded: str) -> None:
+        """Store a payload on disk, if a directory is configured."""
+        if not self._directory:
+            return
+        # Write to a temporary file first, so that concurrent readers never see a
+        # partially written payload.
+        temp_path = f"{self._path(key)}.{os.getpid()}.{threading.get_ident()}.tmp"
+        with open(temp_path, "w", encoding="utf-8") as file:
+            file.write(encoded)
+        os.replace(temp_path, self._path(key))
+        self._evict()
+
+    def _evict(self) -> None:
+        """Remove the least recently used payloads from disk, down to ``max_disk_bytes``."""
+        files = []
+        for entry in os.scandir(self._directory):
+            if entry.name.endswith(".b64"):
+                try:
+                    stat = entry.stat()
+                except FileNotFoundError:
+                    continue
+                files.append((stat.st_mtime, stat.st_size, entry.path))
+        total = sum(size for _, size, _ in files)
+        for _, size, path in sorted(files):
+            if total <= self._max_disk_bytes:
+                break
+            try:
+                os.remove(path)
+            except FileNotFoundError:
+                pass
+            total -= size
+
+
+def _circuit_fingerprint(circuit: QuantumCircuit) -> Tuple[Any, ...]:
+    """Return the parts of a circuit that determine its serialization."""
+    return (
+        circuit.name,
+        circuit.global_phase,
+        repr(circuit.metadata),
+        tuple(circuit.qregs),
+        tuple(circuit.cregs),
+        circuit.layout,
+        tuple(circuit.data),
+    )
+
+
+_payload_cache: Optional[PayloadCache] = None
+
+
+def enable_payload_cache(
+    max_entries: int = DEFAULT_MAX_ENTRIES,
+    directory: Optional[str] = None,
+    max_disk_bytes: int = DEFAULT_MAX_DISK_BYTES,
+) -> PayloadCache:
+    """Reuse the serialized and compressed payloads of circuits submitted repeatedly.
+
+    Example::
+
+        cache = enable_payload_cache()
+        for values in parameter_sweep:
+            sampler.run([(circuit, values)])
+        print(cache.hits, cache.misses)
+
+    Args:
+        max_entries: Maximum number of encoded payloads kept in memory.
+        directory: Directory to also store the encoded payloads in, so that they are
+            reused across processes.
+        max_disk_bytes: Maximum total size, in bytes, of the payloads stored on disk.
+
+    Returns:
+        The payload cache used to encode circuits.
+    """
+    global _payload_cache  # pylint: disable=global-statement
+    _payload_cache = PayloadCache(
+        max_entries=max_entries, directory=directory, max_disk_bytes=max_disk_bytes
+    )
+    return _payload_cache
+
+
+def disable_payload_cache() -> None:
+    """Encode circuits every time they are submitted, which is the default."""
+    global _payload_cache  # pylint: disable=global-statement
+    _payload_cache = None
+
+
+def get_payload_cache() -> Optional[PayloadCache]:
+    """Return the payload cache used to encode circuits, if it is enabled."""
+    return _payload_cache
@@ -0,0 +1,132 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Columnar export of backend properties."""
+
+from typing import Any, Dict, List, Union, TYPE_CHECKING
+
+import numpy as np
+
+from qiskit.providers.models import BackendProperties
+
+from ..exceptions import IBMBackendValueError
+
+if TYPE_CHECKING:
+    from ..ibm_backend import IBMBackend
+
+_QUBIT_COLUMNS = {
+    "t1": "T1",
+    "t2": "T2",
+    "frequency": "frequency",
+    "readout_error": "readout_error",
+}
+
+
+def _get_properties(backend: Union["IBMBackend", BackendProperties]) -> BackendProperties:
+    """Return the properties of a backend, or the properties themselves."""
+    if isinstance(backend, BackendProperties):
+        return backend
+    properties = backend.properties()
+    if properties is None:
+        raise IBMBackendValueError(f"Backend {backend.name} does not have properties.")
+    return properties
+
+
+def properties_dataframe(
+    backend: Union["IBMBackend", BackendProperties]
+) -> Dict[str, np.ndarray]:
+    """Return the qubit properties of a backend as columnar arrays.
+
+    The returned dictionary has one entry per column and one array element per qubit,
+    so it can be passed directly to ``pandas.DataFrame`` or ``polars.DataFrame``.
+    The columns are:
+
+        * ``qubit``: Index of the qubit.
+        * ``t1``: T1 time, in seconds.
+        * ``t2``: T2 time, in seconds.
+        * ``frequency``: Qubit frequency, in Hz.
+        * ``readout_error``: Readout assignment error.
+        * ``<gate>_error``: Error of each single-qubit gate, e.g. ``sx_error``.
+
+    Missing values are set to ``NaN``.
+
+    Args:
+        backend: Backend, or backend properties, to export.
+
+    Returns:
+        Qubit properties, keyed by column name.
+
+    Raises:
+        IBMBackendValueError: If the backend does not have properties.
+    """
+    properties = _get_properties(backend)
+    num_qubits = len(properties.qubits)
+    columns: Dict[str, np.ndarray] = {"qubit": np.arange(num_qubits)}
+    for column, name in _QUBIT_COLUMNS.items():
+        columns[column] = np.full(num_qubits, np.nan)
+        for qubit in range(num_qubits):
+            value = properties.qubit_property(qubit).get(name)
+            if value is not None:
+                columns[column][qubit] = value[0]
+
+    for gate in properties.gates:
+        if len(gate.qubits) != 1:
+            continue
+        column = f"{gate.gate}_error"
+        if column not in columns:
+            columns[column] = np.full(num_qubits, np.nan)
+        value = properties.gate_property(gate.gate, gate.qubits).get("gate_error")
+        if value is not None:
+            columns[column][gate.qubits[0]] = value[0]
+
+    return columns
+
+
+def gate_properties_dataframe(
+    backend: Union["IBMBackend", BackendProperties]
+) -> Dict[str, Union[np.ndarray, List[Any]]]:
+    """Return the gate properties of a backend as columnar arrays.
+
+    The returned dictionary has one entry per column and one array element per gate
+    instance, so it can be passed directly to ``pandas.DataFrame`` or ``polars.DataFrame``.
+    The columns are:
+
+        * ``gate``: Name of the gate, e.g. ``ecr``.
+        * ``qubits``: Tuple of the qubits the gate acts on.
+        * ``gate_error``: Gate error.
+        * ``gate_length``: Gate length, in seconds.
+
+    Missing values are set to ``NaN``.
+
+    Args:
+        backend: Backend, or backend properties, to export.
+
+    Returns:
+        Gate properties, keyed by column name.
+
+    Raises:
+        IBMBackendValueError: If the backend does not have properties.
+    """
+    properties = _get_properties(backend)
+    gates = properties.gates
+    columns: Dict[str, Union[np.ndarray, List[Any]]] = {
+        "gate": np.array([gate.gate for gate in gates], dtype=str),
+        "qubits": [tuple(gate.qubits) for gate in gates],
+    }
+    for name in ("gate_error", "gate_length"):
+        values = np.full(len(gates), np.nan)
+        for index, gate in enumerate(gates):
+            value = properties.gate_property(gate.gate, gate.qubits).get(name)
+            if value is not None:
+                values[index] = value[0]
+        columns[name] = values
+    return columns
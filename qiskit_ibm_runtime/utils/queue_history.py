@@ -0,0 +1,140 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Client-side history of backend queue lengths."""
+
+import logging
+import threading
+from collections import deque
+from datetime import datetime, timezone
+from typing import Any, Deque, Dict, List, Optional, Sequence, Tuple
+
+logger = logging.getLogger(__name__)
+
+
+class QueueLengthSampler:
+    """Periodically record the number of pending jobs of backends.
+
+    The most recent samples of each backend are kept in a ring buffer and can be
+    queried as a series, for example to pick the backend whose queue is shrinking.
+
+    Example::
+
+        backends = [service.backend("ibm_kyoto"), service.backend("ibm_osaka")]
+        with QueueLengthSampler(backends, interval=60) as sampler:
+            ...
+            series = sampler.series("ibm_kyoto")
+    """
+
+    def __init__(self, backends: Sequence[Any], interval: float = 60, max_samples: int = 1440):
+        """QueueLengthSampler constructor.
+
+        Args:
+            backends: Backends to sample.
+            interval: Number of seconds between samples.
+            max_samples: Maximum number of samples kept per backend. Older samples
+                are discarded first.
+
+        Raises:
+            ValueError: If ``interval`` or ``max_samples`` is not positive.
+        """
+        if interval <= 0:
+            raise ValueError("The sampling interval must be positive.")
+        if max_samples <= 0:
+            raise ValueError("The maximum number of samples must be positive.")
+        self._backends = list(backends)
+        self._interval = interval
+        self._history: Dict[str, Deque[Tuple[datetime, int]]] = {
+            backend.name: deque(maxlen=max_samples) for backend in self._backends
+        }
+        self._lock = threading.Lock()
+        self._stop_event = threading.Event()
+        self._thread: Optional[threading.Thread] = None
+
+    def record(self) -> None:
+        """Record the current number of pending jobs of every backend.
+
+        Backends whose status cannot be retrieved are skipped, so that a failure does not
+        stop the sampling thread.
+        """
+        for backend in self._backends:
+            try:
+                pending_jobs = backend.status().pending_jobs
+            except Exception as ex:  # pylint: disable=broad-except
+                logger.warning("Unable to retrieve the status of %s: %s", backend.name, ex)
+                continue
+            with self._lock:
+                self._history[backend.name].append((datetime.now(timezone.utc), pending_jobs))
+
+    def start(self) -> None:
+        """Start sampling in a background thread."""
+        if self._thread is not None and self._thread.is_alive():
+            return
+        self._stop_event.clear()
+        self._thread = threading.Thread(target=self._run, daemon=True)
+        self._thread.start()
+
+    def stop(self) -> None:
+        """Stop sampling."""
+        self._stop_event.set()
+        if self._thread is not None:
+            self._thread.join()
+            self._thread = None
+
+    def series(self, backend_name: str) -> List[Tuple[datetime, int]]:
+        """Return the recorded samples of a backend.
+
+        Args:
+            backend_name: Name of the backend.
+
+        Returns:
+            A list of ``(timestamp, pending_jobs)`` tuples, oldest first. Timestamps are in UTC.
+
+        Raises:
+            ValueError: If the backend is not sampled.
+        """
+        if backend_name not in self._history:
+            raise ValueError(f"Backend {backend_name} is not sampled.")
+        with self._lock:
+            return list(self._history[backend_name])
+
+    def trend(self, backend_name: str) -> Optional[float]:
+        """Return the average change in pending jobs per hour over the recorded samples.
+
+        Args:
+            backend_name: Name of the backend.
+
+        Returns:
+            The change in pending jobs per hour, negative if the queue is shrinking, or
+            ``None`` if fewer than two samples were recorded.
+        """
+        series = self.series(backend_name)
+        if len(series) < 2:
+            return None
+        (first_time, first_count), (last_time, last_count) = series[0], series[-1]
+        elapsed = (last_time - first_time).total_seconds()
+        if elapsed <= 0:
+            return None
+        return (last_count - first_count) * 3600 / elapsed
+
+    def _run(self) -> None:
+        """Record samples until stopped."""
+        while not self._stop_event.is_set():
+            self.record()
+            self._stop_event.wait(self._interval)
+
+    def __enter__(self) -> "QueueLengthSampler":
+        self.start()
+        return self
+
+    def __exit__(self, *exc: Any) -> None:
+        self.stop()
@@ -0,0 +1,127 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Readout error mitigation of counts with backend calibration data."""
+
+from typing import Dict, List, Mapping, Sequence, Union, TYPE_CHECKING
+
+import numpy as np
+
+from qiskit.providers.models import BackendProperties
+
+from ..exceptions import IBMBackendValueError, IBMInputValueError
+from .properties_export import _get_properties
+
+if TYPE_CHECKING:
+    from ..ibm_backend import IBMBackend
+
+MAX_MITIGATED_QUBITS = 20
+"""Maximum number of qubits whose counts can be mitigated."""
+
+
+def assignment_matrices(
+    backend: Union["IBMBackend", BackendProperties], qubits: Sequence[int]
+) -> List[np.ndarray]:
+    """Return the readout assignment matrices of qubits.
+
+    The element ``[i, j]`` of a matrix is the probability of measuring ``i`` when the
+    qubit is prepared in ``j``. The matrices are built from the ``prob_meas1_prep0`` and
+    ``prob_meas0_prep1`` properties of the qubits, or from their symmetric
+    ``readout_error`` if those are not reported.
+
+    Args:
+        backend: Backend, or backend properties, to read the calibration data from.
+        qubits: Physical qubits.
+
+    Returns:
+        The assignment matrix of each qubit, in the order of ``qubits``.
+
+    Raises:
+        IBMBackendValueError: If the backend does not have properties, or a qubit
+            does not have readout calibration data.
+    """
+    properties = _get_properties(backend)
+    matrices = []
+    for qubit in qubits:
+        qubit_properties = properties.qubit_property(qubit)
+        if "prob_meas1_prep0" in qubit_properties and "prob_meas0_prep1" in qubit_properties:
+            p10 = qubit_properties["prob_meas1_prep0"][0]
+            p01 = qubit_properties["prob_meas0_prep1"][0]
+        elif "readout_error" in qubit_properties:
+            p10 = p01 = qubit_properties["readout_error"][0]
+        else:
+            raise IBMBackendValueError(f"Qubit {qubit} does not have readout calibration data.")
+        matrices.append(np.array([[1 - p10, p01], [p10, 1 - p01]]))
+    return matrices
+
+
+def mitigate_counts(
+    counts: Mapping[str, int],
+    backend: Union["IBMBackend", BackendProperties],
+    qubits: Sequence[int],
+) -> Dict[str, float]:
+    """Correct counts for readout errors by inverting the tensored assignment matrix.
+
+    Readout errors are assumed to be uncorrelated between qubits, so the inverse of the
+    assignment matrix of each qubit is applied separately. The result is a
+    quasi-probability distribution, which can have negative values.
+
+    Example::
+
+        counts = job.result()[0].data.meas.get_counts()
+        quasi_dist = mitigate_counts(counts, backend, qubits=[12, 13])
+
+    Args:
+        counts: Counts, keyed by bitstring. The rightmost bit is the measurement of
+            ``qubits[0]``.
+        backend: Backend, or backend properties, to read the calibration data from.
+        qubits: Physical qubits that were measured, in the order of the bits.
+
+    Returns:
+        The mitigated quasi-probabilities, keyed by bitstring. Bitstrings whose
+        quasi-probability is zero are omitted.
+
+    Raises:
+        IBMInputValueError: If there are too many qubits, or the bitstrings do not
+            match the number of qubits.
+        IBMBackendValueError: If the backend does not have readout calibration data.
+    """
+    num_qubits = len(qubits)
+    if num_qubits > MAX_MITIGATED_QUBITS:
+        raise IBMInputValueError(
+            f"Readout mitigation is limited to {MAX_MITIGATED_QUBITS} qubits, "
+            f"but {num_qubits} were given."
+        )
+    shots = sum(counts.values())
+    if num_qubits == 0 or shots == 0:
+        raise IBMInputValueError("The counts and qubits must not be empty.")
+
+    probabilities = np.zeros(2**num_qubits)
+    for bitstring, count in counts.items():
+        bits = bitstring.replace(" ", "")
+        if len(bits) != num_qubits:
+            raise IBMInputValueError(
+                f"Bitstring {bitstring} does not have one bit per qubit ({num_qubits})."
+            )
+        probabilities[int(bits, 2)] += count / shots
+
+    # The first axis is the leftmost bit, which is the measurement of the last qubit.
+    tensor = probabilities.reshape((2,) * num_qubits)
+    for axis, matrix in enumerate(reversed(assignment_matrices(backend, qubits))):
+        corrected = np.tensordot(np.linalg.inv(matrix), tensor, axes=([1], [axis]))
+        tensor = np.moveaxis(corrected, 0, axis)
+
+    return {
+        format(index, f"0{num_qubits}b"): float(value)
+        for index, value in enumerate(tensor.reshape(-1))
+        if value != 0
+    }
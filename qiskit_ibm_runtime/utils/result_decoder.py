@@ -13,9 +13,10 @@
 """Qiskit runtime job result decoder."""
 
 import json
-from typing import Any
+from typing import Any, Sequence
 
 from qiskit_ibm_runtime.utils import RuntimeDecoder
+from ..exceptions import IBMInputValueError
 
 
 class ResultDecoder:
@@ -51,3 +52,34 @@ class ResultDecoder:
             return json.loads(data, cls=RuntimeDecoder)
         except json.JSONDecodeError:
             return data
+
+
+def select_pub_results(data: str, pub_indices: Sequence[int]) -> str:
+    """Keep only the given PUB results in raw ``PrimitiveResult`` data.
+
+    This allows decoding the results of a subset of the PUBs of a job without
+    decoding the results of all the other PUBs.
+
+    Args:
+        data: Raw result data of a V2 primitive job.
+        pub_indices: Indices of the PUB results to keep, in the order they should
+            be returned.
+
+    Returns:
+        Raw result data containing only the selected PUB results.
+
+    Raises:
+        IBMInputValueError: If the data is not a ``PrimitiveResult`` or an index
+            is out of range.
+    """
+    raw_result = json.loads(data)
+    if not isinstance(raw_result, dict) or raw_result.get("__type__") != "PrimitiveResult":
+        raise IBMInputValueError("PUB results can only be selected from primitive V2 results.")
+    pub_results = raw_result["__value__"]["pub_results"]
+    for index in pub_indices:
+        if not -len(pub_results) <= index < len(pub_results):
+            raise IBMInputValueError(
+                f"PUB index {index} is out of range for a result with {len(pub_results)} PUBs."
+            )
+    raw_result["__value__"]["pub_results"] = [pub_results[index] for index in pub_indices]
+    return json.dumps(raw_result)
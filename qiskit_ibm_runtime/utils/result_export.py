@@ -0,0 +1,106 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Export of sampler results to Apache Arrow and Parquet."""
+
+from typing import Any, Union
+from pathlib import Path
+
+import numpy as np
+
+from qiskit.exceptions import MissingOptionalLibraryError
+from qiskit.primitives.containers import BitArray, PrimitiveResult
+
+try:
+    import pyarrow
+    import pyarrow.parquet
+
+    HAS_PYARROW = True
+except ImportError:
+    HAS_PYARROW = False
+
+
+def sampler_result_to_arrow(result: PrimitiveResult) -> "pyarrow.Table":
+    """Convert the shot data of a sampler result to an Apache Arrow table.
+
+    The table has one row per shot and classical register, with the following columns:
+
+        * ``pub_index``: Index of the PUB in the result.
+        * ``register``: Name of the classical register.
+        * ``position``: Flat index into the shape of the PUB, for parameter sweeps.
+        * ``shot``: Index of the shot.
+        * ``bitstring``: Measured bitstring.
+
+    The table can be loaded into pandas or polars with ``table.to_pandas()`` or
+    ``polars.from_arrow(table)``.
+
+    Args:
+        result: Result of a ``SamplerV2`` job.
+
+    Returns:
+        Arrow table with the shot data.
+
+    Raises:
+        MissingOptionalLibraryError: If ``pyarrow`` is not installed.
+    """
+    if not HAS_PYARROW:
+        raise MissingOptionalLibraryError(
+            libname="pyarrow",
+            name="sampler_result_to_arrow",
+            pip_install="pip install pyarrow",
+        )
+
+    pub_indices = []
+    registers = []
+    positions = []
+    shots = []
+    bitstrings = []
+    for pub_index, pub_result in enumerate(result):
+        for register, value in pub_result.data.items():
+            if not isinstance(value, BitArray):
+                continue
+            num_rows = value.size * value.num_shots
+            pub_indices.append(np.full(num_rows, pub_index, dtype=np.int32))
+            registers.extend([register] * num_rows)
+            positions.append(np.repeat(np.arange(value.size, dtype=np.int64), value.num_shots))
+            shots.append(np.tile(np.arange(value.num_shots, dtype=np.int64), value.size))
+            bitstrings.extend(value.get_bitstrings())
+
+    def _concatenate(arrays: list, dtype: Any) -> np.ndarray:
+        return np.concatenate(arrays) if arrays else np.array([], dtype=dtype)
+
+    return pyarrow.table(
+        {
+            "pub_index": _concatenate(pub_indices, np.int32),
+            "register": pyarrow.array(registers, type=pyarrow.string()).dictionary_encode(),
+            "position": _concatenate(positions, np.int64),
+            "shot": _concatenate(shots, np.int64),
+            "bitstring": pyarrow.array(bitstrings, type=pyarrow.string()),
+        }
+    )
+
+
+def sampler_result_to_parquet(
+    result: PrimitiveResult, path: Union[str, Path], **kwargs: Any
+) -> None:
+    """Write the shot data of a sampler result to a Parquet file.
+
+    The file contains the table described in :func:`sampler_result_to_arrow`.
+
+    Args:
+        result: Result of a ``SamplerV2`` job.
+        path: Path of the Parquet file to write.
+        **kwargs: Additional arguments passed to ``pyarrow.parquet.write_table``,
+            such as ``compression``.
+    """
+    table = sampler_result_to_arrow(result)
+    pyarrow.parquet.write_table(table, str(path), **kwargs)
@@ -0,0 +1,111 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""States of a session and the transitions between them."""
+
+from enum import Enum
+from typing import Dict, FrozenSet, Optional
+
+
+class SessionState(str, Enum):
+    """State of a session, as reported by the server.
+
+    The members are strings, so they compare equal to the states in the session
+    details, for example ``SessionState.CLOSED == "closed"``.
+    """
+
+    OPEN = "open"
+    """The session is created, and will become active when its first job starts."""
+    ACTIVE = "active"
+    """A job of the session is running, or the session is waiting for its next job."""
+    INACTIVE = "inactive"
+    """The interactive timeout expired. The session becomes active again when its next
+    job starts."""
+    PENDING_INACTIVE = "pending_inactive"
+    """The session is active and will become inactive once its jobs are done."""
+    PENDING_CLOSED = "pending_closed"
+    """The session no longer accepts jobs and will be closed once its jobs are done."""
+    CLOSED = "closed"
+    """The session was closed or cancelled, or reached its maximum time."""
+
+    @classmethod
+    def parse(cls, state: Optional[str]) -> Optional["SessionState"]:
+        """Return the session state matching a state string, ignoring its case.
+
+        Args:
+            state: State of the session, as reported by the server.
+
+        Returns:
+            The session state, or ``None`` if the state is unknown.
+        """
+        try:
+            return cls(state.lower()) if state else None
+        except ValueError:
+            return None
+
+    def can_transition_to(self, state: "SessionState") -> bool:
+        """Return whether a session in this state can move to another state.
+
+        Args:
+            state: Next state.
+
+        Returns:
+            Whether the transition is legal. Staying in the same state is legal.
+        """
+        return state == self or state in SESSION_STATE_TRANSITIONS[self]
+
+    def can_reach(self, state: "SessionState") -> bool:
+        """Return whether a session in this state can end up in another state, through
+        any number of transitions.
+
+        Args:
+            state: Later state.
+
+        Returns:
+            Whether the state can be reached.
+        """
+        reachable = {self}
+        pending = [self]
+        while pending:
+            for next_state in SESSION_STATE_TRANSITIONS[pending.pop()] - reachable:
+                reachable.add(next_state)
+                pending.append(next_state)
+        return state in reachable
+
+
+SESSION_STATE_TRANSITIONS: Dict[SessionState, FrozenSet[SessionState]] = {
+    SessionState.OPEN: frozenset(
+        {SessionState.ACTIVE, SessionState.PENDING_CLOSED, SessionState.CLOSED}
+    ),
+    SessionState.ACTIVE: frozenset(
+        {
+            SessionState.INACTIVE,
+            SessionState.PENDING_INACTIVE,
+            SessionState.PENDING_CLOSED,
+            SessionState.CLOSED,
+        }
+    ),
+    SessionState.INACTIVE: frozenset(
+        {SessionState.ACTIVE, SessionState.PENDING_CLOSED, SessionState.CLOSED}
+    ),
+    SessionState.PENDING_INACTIVE: frozenset(
+        {
+            SessionState.ACTIVE,
+            SessionState.INACTIVE,
+            SessionState.PENDING_CLOSED,
+            SessionState.CLOSED,
+        }
+    ),
+    SessionState.PENDING_CLOSED: frozenset({SessionState.CLOSED}),
+    SessionState.CLOSED: frozenset(),
+}
+"""Legal next states of each session state."""
@@ -0,0 +1,127 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Splitting of sampler PUBs that exceed the maximum number of shots of a backend."""
+
+from typing import List, Optional, Sequence, Tuple
+
+import numpy as np
+
+from qiskit.primitives.containers import BitArray, DataBin, PrimitiveResult, SamplerPubResult
+from qiskit.primitives.containers.sampler_pub import SamplerPub
+
+from ..exceptions import IBMInputValueError
+
+
+def split_sampler_pubs(
+    pubs: Sequence[SamplerPub], max_shots: int
+) -> Tuple[List[SamplerPub], List[List[Optional[int]]]]:
+    """Split the PUBs whose number of shots exceeds ``max_shots``.
+
+    Each such PUB is replaced by several copies whose shots add up to the requested
+    number of shots, each within ``max_shots``.
+
+    Args:
+        pubs: PUBs to split.
+        max_shots: Maximum number of shots of a single PUB.
+
+    Returns:
+        A tuple with the split PUBs and, for each input PUB, the list of shots of the
+        PUBs it was split into.
+    """
+    split_pubs = []
+    splits = []
+    for pub in pubs:
+        if pub.shots is None or pub.shots <= max_shots:
+            split_pubs.append(pub)
+            splits.append([pub.shots])
+            continue
+        num_parts = -(-pub.shots // max_shots)
+        base_shots, remainder = divmod(pub.shots, num_parts)
+        shots = [base_shots + 1] * remainder + [base_shots] * (num_parts - remainder)
+        split_pubs.extend(
+            SamplerPub(pub.circuit, pub.parameter_values, shots=part_shots, validate=False)
+            for part_shots in shots
+        )
+        splits.append(shots)
+    return split_pubs, splits
+
+
+def split_pub_indices(
+    splits: Sequence[Sequence[Optional[int]]], pub_indices: Optional[Sequence[int]] = None
+) -> Tuple[List[int], List[List[Optional[int]]]]:
+    """Map indices of the original PUBs to the indices of the PUBs they were split into.
+
+    Args:
+        splits: Shots of the split PUBs, as returned by :func:`split_sampler_pubs`.
+        pub_indices: Indices of the original PUBs. If ``None``, all PUBs are selected.
+
+    Returns:
+        A tuple with the indices of the split PUBs and the splits of the selected PUBs.
+
+    Raises:
+        IBMInputValueError: If an index is out of range.
+    """
+    offsets = np.cumsum([0] + [len(shots) for shots in splits])
+    if pub_indices is None:
+        pub_indices = range(len(splits))
+    indices = []
+    selected_splits = []
+    for index in pub_indices:
+        if not -len(splits) <= index < len(splits):
+            raise IBMInputValueError(
+                f"PUB index {index} is out of range for a result with {len(splits)} PUBs."
+            )
+        index %= len(splits)
+        start = int(offsets[index])
+        indices.extend(range(start, start + len(splits[index])))
+        selected_splits.append(list(splits[index]))
+    return indices, selected_splits
+
+
+def merge_sampler_results(
+    result: PrimitiveResult, splits: Sequence[Sequence[Optional[int]]]
+) -> PrimitiveResult:
+    """Merge the results of split PUBs back into one result per original PUB.
+
+    The samples of the split PUBs are concatenated, so that counts are summed. The shots
+    of each part are reported in the ``shots_split`` metadata field of merged PUB results.
+
+    Args:
+        result: Result of the job that ran the split PUBs.
+        splits: Shots of the split PUBs, as returned by :func:`split_sampler_pubs`.
+
+    Returns:
+        The merged result.
+    """
+    pub_results = []
+    position = 0
+    for shots in splits:
+        parts = result[position : position + len(shots)]
+        position += len(shots)
+        if len(parts) == 1:
+            pub_results.append(parts[0])
+            continue
+        first = parts[0]
+        merged_data = {}
+        for name, value in first.data.items():
+            values = [part.data[name] for part in parts]
+            if isinstance(value, BitArray):
+                merged_data[name] = BitArray.concatenate_shots(values)
+            else:
+                merged_data[name] = np.concatenate(values, axis=len(first.data.shape))
+        metadata = dict(first.metadata)
+        metadata["shots_split"] = list(shots)
+        pub_results.append(
+            SamplerPubResult(DataBin(**merged_data, shape=first.data.shape), metadata=metadata)
+        )
+    return PrimitiveResult(pub_results, metadata=result.metadata)
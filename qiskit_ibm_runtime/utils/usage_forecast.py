@@ -0,0 +1,99 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Forecast of the usage of an instance against its quota."""
+
+from dataclasses import dataclass
+from datetime import datetime, timedelta, timezone
+from typing import Any, Dict, Optional
+
+from .converters import str_to_utc
+
+SECONDS_PER_DAY = 24 * 60 * 60
+
+
+@dataclass
+class UsageForecast:
+    """Projection of the usage of an instance over its current usage period."""
+
+    consumed_seconds: float
+    """Usage consumed in the current period, in seconds."""
+    limit_seconds: Optional[float]
+    """Usage limit of the period, in seconds, or ``None`` if the instance has no limit."""
+    remaining_seconds: Optional[float]
+    """Usage remaining in the period, in seconds, or ``None`` if the instance has no limit."""
+    period_end: Optional[datetime]
+    """End of the current usage period, if reported."""
+    daily_rate_seconds: float
+    """Usage per day the projection is based on, in seconds: the recent daily usage if it
+    is known, otherwise the average usage per day since the start of the period."""
+    projected_exhaustion: Optional[datetime]
+    """Time at which the limit is reached at the average daily rate, or ``None`` if it is
+    not reached before the end of the period."""
+    daily_budget_seconds: Optional[float]
+    """Usage that can be consumed per day until the end of the period without reaching the
+    limit, in seconds, or ``None`` if the instance has no limit or period end."""
+
+
+def forecast_usage(
+    usage: Dict[str, Any],
+    now: Optional[datetime] = None,
+    recent_daily_rate: Optional[float] = None,
+) -> UsageForecast:
+    """Project the usage of an instance from the usage reported by the server.
+
+    Args:
+        usage: Usage of the instance, as returned by the ``/usage`` endpoint.
+        now: Current time. Defaults to the current UTC time.
+        recent_daily_rate: Average usage per day over the recent days, in seconds. It
+            follows changes in the pace of usage better than the average since the start
+            of the period, which is used if it is not given.
+
+    Returns:
+        The usage forecast.
+    """
+    now = now or datetime.now(timezone.utc)
+    consumed = float(usage.get("usage_consumed_seconds") or 0)
+    limit = usage.get("usage_limit_seconds")
+    remaining = usage.get("usage_remaining_seconds")
+    if remaining is None and limit is not None:
+        remaining = max(limit - consumed, 0)
+    period = usage.get("usage_period") or {}
+    period_start = str_to_utc(period.get("start_time"))
+    period_end = str_to_utc(period.get("end_time"))
+
+    daily_rate = 0.0
+    if recent_daily_rate is not None:
+        daily_rate = recent_daily_rate
+    elif period_start is not None and now > period_start:
+        daily_rate = consumed * SECONDS_PER_DAY / (now - period_start).total_seconds()
+
+    projected_exhaustion = None
+    if remaining is not None and daily_rate > 0:
+        projected_exhaustion = now + timedelta(days=remaining / daily_rate)
+        if period_end is not None and projected_exhaustion > period_end:
+            projected_exhaustion = None
+
+    daily_budget = None
+    if remaining is not None and period_end is not None and period_end > now:
+        days_left = (period_end - now).total_seconds() / SECONDS_PER_DAY
+        daily_budget = remaining / max(days_left, 1)
+
+    return UsageForecast(
+        consumed_seconds=consumed,
+        limit_seconds=limit,
+        remaining_seconds=remaining,
+        period_end=period_end,
+        daily_rate_seconds=daily_rate,
+        projected_exhaustion=projected_exhaustion,
+        daily_budget_seconds=daily_budget,
+    )
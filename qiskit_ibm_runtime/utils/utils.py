@@ -25,6 +25,8 @@ from urllib.parse import urlparse
 
 import requests
 from ibm_cloud_sdk_core.authenticators import (  # pylint: disable=import-error
+    Authenticator,
+    ContainerAuthenticator,
     IAMAuthenticator,
 )
 from ibm_platform_services import ResourceControllerV2  # pylint: disable=import-error
@@ -32,6 +34,12 @@ from qiskit.circuit import QuantumCircuit, ControlFlowOp
 from qiskit.transpiler import Target
 from qiskit.providers.backend import BackendV1, BackendV2
 
+from ..exceptions import IBMInputValueError
+
+
+# Matches regional runtime API host names, capturing the region and the base host name.
+RE_REGIONAL_RUNTIME_HOST = re.compile(r"^(?:private\.)?([a-z0-9-]+)\.quantum-computing\.(.+)$")
+
 
 def is_simulator(backend: BackendV1 | BackendV2) -> bool:
     """Return true if the backend is a simulator.
@@ -104,7 +112,31 @@ def get_resource_controller_api_url(cloud_url: str) -> str:
     return f"{parsed_url.scheme}://resource-controller.{parsed_url.hostname}"
 
 
-def resolve_crn(channel: str, url: str, instance: str, token: str) -> List[str]:
+def get_cloud_authenticator(
+    url: str, token: Optional[str] = None, trusted_profile: Optional[str] = None
+) -> Authenticator:
+    """Return the IAM authenticator of an IBM Cloud account.
+
+    Args:
+        url: IBM Cloud URL.
+        token: IBM Cloud API key.
+        trusted_profile: ID or name of an IBM Cloud trusted profile, used instead of an
+            API key. The compute resource token of the workload running in IBM Cloud is
+            exchanged for IAM access tokens of the trusted profile.
+
+    Returns:
+        The authenticator.
+    """
+    if trusted_profile:
+        if trusted_profile.startswith("Profile-"):
+            return ContainerAuthenticator(iam_profile_id=trusted_profile, url=get_iam_api_url(url))
+        return ContainerAuthenticator(iam_profile_name=trusted_profile, url=get_iam_api_url(url))
+    return IAMAuthenticator(token, url=get_iam_api_url(url))
+
+
+def resolve_crn(
+    channel: str, url: str, instance: str, token: str, trusted_profile: Optional[str] = None
+) -> List[str]:
     """Resolves the Cloud Resource Name (CRN) for the given cloud account."""
     if channel != "ibm_cloud":
         raise ValueError("CRN value can only be resolved for cloud accounts.")
@@ -115,7 +147,7 @@ def resolve_crn(channel: str, url: str, instance: str, token: str) -> List[str]:
     else:
         with requests.Session() as session:
             # resolve CRN value based on the provided service name
-            authenticator = IAMAuthenticator(token, url=get_iam_api_url(url))
+            authenticator = get_cloud_authenticator(url, token, trusted_profile)
             client = ResourceControllerV2(authenticator=authenticator)
             client.set_service_url(get_resource_controller_api_url(url))
             client.set_http_client(session)
@@ -143,6 +175,9 @@ def is_crn(locator: str) -> bool:
 def get_runtime_api_base_url(url: str, instance: str, private_endpoint: bool = False) -> str:
     """Computes the Runtime API base URL based on the provided input parameters.
 
+    For cloud instances, the region of the API URL is derived from the CRN, unless ``url``
+    already points to a regional endpoint, in which case that endpoint is used.
+
     Args:
         url: The URL.
         instance: The instance.
@@ -150,6 +185,9 @@ def get_runtime_api_base_url(url: str, instance: str, private_endpoint: bool = F
 
     Returns:
         Runtime API base URL
+
+    Raises:
+        IBMInputValueError: If ``url`` points to a region other than the one of the CRN.
     """
 
     # ibm_quantum: no need to resolve runtime API URL
@@ -158,16 +196,20 @@ def get_runtime_api_base_url(url: str, instance: str, private_endpoint: bool = F
     # cloud: compute runtime API URL based on crn and URL
     if is_crn(instance) and not _is_experimental_runtime_url(url):
         parsed_url = urlparse(url)
+        location = _location_from_crn(instance)
+        hostname = parsed_url.hostname
+        regional_match = RE_REGIONAL_RUNTIME_HOST.match(hostname or "")
+        if regional_match:
+            url_location, hostname = regional_match.group(1), regional_match.group(2)
+            if url_location != location:
+                raise IBMInputValueError(
+                    f"The URL {url} points to the {url_location} region, but the instance "
+                    f"is in the {location} region."
+                )
         if private_endpoint:
-            api_host = (
-                f"{parsed_url.scheme}://private.{_location_from_crn(instance)}"
-                f".quantum-computing.{parsed_url.hostname}"
-            )
+            api_host = f"{parsed_url.scheme}://private.{location}.quantum-computing.{hostname}"
         else:
-            api_host = (
-                f"{parsed_url.scheme}://{_location_from_crn(instance)}"
-                f".quantum-computing.{parsed_url.hostname}"
-            )
+            api_host = f"{parsed_url.scheme}://{location}.quantum-computing.{hostname}"
 
     return api_host
 
@@ -11,7 +11,7 @@
 # that they have been altered from the originals.
 
 """Utilities for data validation."""
-from typing import List, Sequence, Optional, Any
+from typing import Dict, List, Sequence, Optional, Any
 import warnings
 import keyword
 from qiskit import QuantumCircuit
@@ -125,3 +125,34 @@ def validate_job_tags(job_tags: Optional[List[str]]) -> None:
         not isinstance(job_tags, list) or not all(isinstance(tag, str) for tag in job_tags)
     ):
         raise IBMInputValueError("job_tags needs to be a list of strings.")
+
+
+def validate_options_profile(profile: Dict[str, Any], backend: Any) -> None:
+    """Validate that the options of a profile are supported by a backend.
+
+    Args:
+        profile: Options of the profile.
+        backend: Backend the options are used with.
+
+    Raises:
+        IBMInputValueError: If the number of shots or the repetition delay is outside
+            the range supported by the backend.
+    """
+    configuration = backend.configuration() if hasattr(backend, "configuration") else None
+    if configuration is None:
+        return
+    shots = profile.get("default_shots")
+    max_shots = getattr(configuration, "max_shots", None)
+    if shots is not None and max_shots is not None and shots > max_shots:
+        raise IBMInputValueError(
+            f"The profile uses {shots} shots, but {backend.name} supports at most {max_shots}."
+        )
+    rep_delay = (profile.get("execution") or {}).get("rep_delay")
+    rep_delay_range = getattr(configuration, "rep_delay_range", None)
+    if rep_delay is not None and rep_delay_range:
+        low, high = rep_delay_range
+        if not low <= rep_delay <= high:
+            raise IBMInputValueError(
+                f"The profile uses a repetition delay of {rep_delay} s, but {backend.name} "
+                f"supports delays between {low} s and {high} s."
+            )
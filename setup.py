@@ -22,7 +22,7 @@ REQUIREMENTS = [
     "requests>=2.19",
     "requests-ntlm>=1.1.0",
     "numpy>=1.13",
-    "urllib3>=1.21.1",
+    "urllib3>=1.26",
     "python-dateutil>=2.8.0",
     "websocket-client>=1.5.1",
     "ibm-platform-services>=0.22.6",
@@ -107,6 +107,7 @@ class BaseFakeRuntimeJob:
         max_execution_time=None,
         start_session=None,
         channel_strategy=None,
+        user_id="fake_user",
     ):
         """Initialize a fake job."""
         self._job_id = job_id
@@ -133,6 +134,9 @@ class BaseFakeRuntimeJob:
             self._result = json.dumps({"quasi_dists": [{0: 0.5, 3: 0.5}], "metadata": []})
         self._final_status = final_status
         self._channel_strategy = channel_strategy
+        self._user_id = user_id
+        self._results_expiration = None
+        self._usage = None
 
     def _auto_progress(self):
         """Automatically update job status."""
@@ -155,6 +159,9 @@ class BaseFakeRuntimeJob:
             "params": self._params,
             "program": {"id": self._program_id},
             "image": self._image,
+            "user_id": self._user_id,
+            "results_expiration": self._results_expiration,
+            "usage": self._usage,
         }
 
     def result(self):
@@ -392,6 +399,7 @@ class BaseFakeRuntimeClient:
             jobs = [job for job in jobs if job._session_id == session_id]
         if created_after:
             jobs = [job for job in jobs if job._creation_date >= created_after]
+        if created_before:
             jobs = [job for job in jobs if job._creation_date <= created_before]
 
         count = len(jobs)
@@ -445,11 +453,20 @@ class BaseFakeRuntimeClient:
             return ret.copy()
         return None
 
+    # pylint: disable=unused-argument
+    def backend_configurations(
+        self, backend_names: List[str], max_workers: int = 8
+    ) -> Dict[str, Dict[str, Any]]:
+        """Return the configurations of several backends."""
+        return {name: self.backend_configuration(name) for name in backend_names}
+
     def backend_status(self, backend_name: str) -> Dict[str, Any]:
         """Return the status of a backend."""
         return self._find_backend(backend_name).status
 
-    def backend_properties(self, backend_name: str, datetime: Any = None) -> Dict[str, Any]:
+    def backend_properties(
+        self, backend_name: str, datetime: Any = None, refresh: bool = False
+    ) -> Dict[str, Any]:
         """Return the properties of a backend."""
         if datetime:
             raise NotImplementedError("'datetime' is not supported.")
@@ -457,6 +474,14 @@ class BaseFakeRuntimeClient:
             return ret.copy()
         return None
 
+    def backend_snapshot(self, backend_name: str) -> Dict[str, Any]:
+        """Return the configuration, properties and status of a backend."""
+        return {
+            "configuration": self.backend_configuration(backend_name),
+            "properties": self.backend_properties(backend_name),
+            "status": self.backend_status(backend_name),
+        }
+
     def backend_pulse_defaults(self, backend_name: str) -> Dict[str, Any]:
         """Return the pulse defaults of a backend."""
         if ret := self._find_backend(backend_name).defaults:
@@ -483,10 +508,19 @@ class BaseFakeRuntimeClient:
             raise ValueError(f"Session {session_id} not found.")
         self._sessions.remove(session_id)
 
+    def update_session(self, session_id: str, accepting_jobs: bool) -> None:
+        """Update whether the session accepts new jobs."""
+        if session_id not in self._sessions:
+            raise ValueError(f"Session {session_id} not found.")
+
     def session_details(self, session_id: str) -> Dict[str, Any]:
         """Return the details of the session."""
         return {"id": session_id, "mode": "dedicated", "backend_name": "common_backend"}
 
+    def server_time(self):
+        """Return the current time according to the server clock."""
+        return python_datetime.now(timezone.utc)
+
     def _find_backend(self, backend_name):
         for back in self._backends:
             if back.name == backend_name:
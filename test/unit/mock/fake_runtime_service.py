@@ -139,3 +139,7 @@ class FakeAuthClient(AuthClient):
     def current_access_token(self):
         """Return access token."""
         return "some_token"
+
+    def current_user_id(self):
+        """Return user id."""
+        return "fake_user"
@@ -15,11 +15,15 @@ import copy
 import json
 import logging
 import os
+import tempfile
 import uuid
 from typing import Any
 from unittest import skipIf
+from unittest.mock import patch, MagicMock
 
 from qiskit_ibm_runtime.proxies import ProxyConfiguration
+from qiskit_ibm_runtime.exceptions import IBMInputValueError
+from qiskit_ibm_runtime.version import __version__
 from qiskit_ibm_runtime.accounts import (
     AccountManager,
     Account,
@@ -89,7 +93,7 @@ class TestAccount(IBMTestCase):
     def test_invalid_token(self):
         """Test invalid values for token parameter."""
 
-        invalid_tokens = [1, None, ""]
+        invalid_tokens = [1, None, "", "token\n", " token", "tok en", "tökén"]
         for token in invalid_tokens:
             with self.subTest(token=token):
                 with self.assertRaises(InvalidAccountError) as err:
@@ -119,6 +123,7 @@ class TestAccount(IBMTestCase):
             {"channel": "ibm_cloud", "instance": ""},
             {"channel": "ibm_cloud"},
             {"channel": "ibm_quantum", "instance": "no-hgp-format"},
+            {"channel": "ibm_cloud", "instance": "crn:v1:bluemix:public:quantum-computing\n"},
         ]
         for params in subtests:
             with self.subTest(params=params):
@@ -422,6 +427,84 @@ class TestAccountManager(IBMTestCase):
         account = AccountManager.get(filename=_TEST_FILENAME)
         self.assertTrue(account.private_endpoint)
 
+    def test_save_option_profiles(self):
+        """Test saving option profiles with the account."""
+        profiles = {"fast": {"default_shots": 1000}, "precise": {"default_shots": 20000}}
+        AccountManager.save(
+            filename=_TEST_FILENAME,
+            name=_DEFAULT_ACCOUNT_NAME_IBM_QUANTUM,
+            token=_TEST_IBM_QUANTUM_ACCOUNT.token,
+            channel="ibm_quantum",
+            overwrite=True,
+            option_profiles=profiles,
+        )
+
+        account = AccountManager.get(
+            filename=_TEST_FILENAME, name=_DEFAULT_ACCOUNT_NAME_IBM_QUANTUM
+        )
+        self.assertEqual(account.option_profiles, profiles)
+
+        with self.assertRaises(InvalidAccountError):
+            Account.create_account(
+                channel="ibm_quantum", token=self.dummy_token, option_profiles={"fast": 1000}
+            ).validate()
+
+    def test_save_defaults(self):
+        """Test saving a default backend and default options with the account."""
+        AccountManager.save(
+            filename=_TEST_FILENAME,
+            name=_DEFAULT_ACCOUNT_NAME_IBM_QUANTUM,
+            token=_TEST_IBM_QUANTUM_ACCOUNT.token,
+            channel="ibm_quantum",
+            overwrite=True,
+            default_backend="ibm_kyoto",
+            default_options={"sampler": {"default_shots": 1000}},
+        )
+
+        account = AccountManager.get(
+            filename=_TEST_FILENAME, name=_DEFAULT_ACCOUNT_NAME_IBM_QUANTUM
+        )
+        self.assertEqual(account.default_backend, "ibm_kyoto")
+        self.assertEqual(account.default_options, {"sampler": {"default_shots": 1000}})
+
+        for defaults in (
+            {"default_backend": ""},
+            {"default_options": ["default_shots"]},
+            {"default_options": {"default_shots": 1000}},
+        ):
+            with self.subTest(defaults=defaults):
+                with self.assertRaises(InvalidAccountError):
+                    Account.create_account(
+                        channel="ibm_quantum", token=self.dummy_token, **defaults
+                    ).validate()
+
+    def test_save_trusted_profile(self):
+        """Test saving a cloud account that authenticates with a trusted profile."""
+        AccountManager.save(
+            filename=_TEST_FILENAME,
+            name=_DEFAULT_ACCOUNT_NAME_IBM_CLOUD,
+            channel="ibm_cloud",
+            instance=_TEST_IBM_CLOUD_ACCOUNT.instance,
+            overwrite=True,
+            trusted_profile="Profile-0123-abcd",
+        )
+
+        account = AccountManager.get(filename=_TEST_FILENAME, name=_DEFAULT_ACCOUNT_NAME_IBM_CLOUD)
+        self.assertIsNone(account.token)
+        self.assertEqual(account.trusted_profile, "Profile-0123-abcd")
+
+        with self.assertRaises(InvalidAccountError):
+            Account.create_account(
+                channel="ibm_cloud",
+                token=self.dummy_token,
+                instance=_TEST_IBM_CLOUD_ACCOUNT.instance,
+                trusted_profile="Profile-0123-abcd",
+            ).validate()
+        with self.assertRaises(InvalidAccountError):
+            Account.create_account(
+                channel="ibm_quantum", token=None, trusted_profile="Profile-0123-abcd"
+            )
+
     def test_save_default_account(self):
         """Test that if a default_account is defined in the qiskit-ibm.json file,
         this account will be used"""
@@ -851,6 +934,65 @@ class TestEnableAccount(IBMTestCase):
                 self.assertTrue(service._account)
                 self._verify_prefs(extra, service._account)
 
+    def test_version_info(self):
+        """Test version information of an ibm_quantum service."""
+        server_version = {"new_api": True, "api-auth": "0.1"}
+        service = FakeRuntimeService(channel="ibm_quantum", token="abc")
+        with patch("qiskit_ibm_runtime.qiskit_runtime_service.VersionClient") as version_client:
+            version_client.return_value.version.return_value = server_version
+            version_info = service.version_info()
+        self.assertEqual(version_info["client_version"], __version__)
+        self.assertEqual(version_info["channel"], "ibm_quantum")
+        self.assertEqual(version_info["server_version"], server_version)
+        self.assertIn("python_version", version_info)
+        self.assertIn("qiskit_version", version_info)
+
+    def test_instance_groups(self):
+        """Test listing the instances of an ibm_quantum service by hub and group."""
+        service = FakeRuntimeService(channel="ibm_quantum", token="abc")
+        self.assertEqual(
+            [group.name for group in service.instance_groups()], ["hub0/group0", "hub1/group1"]
+        )
+        self.assertEqual(service.instance_groups()[1].instances, ["hub1/group1/project1"])
+        self.assertEqual(service.instances(group="hub1"), ["hub1/group1/project1"])
+        self.assertEqual(service.instances(group="hub0/group0"), ["hub0/group0/project0"])
+        self.assertEqual(service.instances(group="hub0/group1"), [])
+        with self.assertRaises(IBMInputValueError):
+            service.instances(group="hub0/group0/project0")
+
+        service = FakeRuntimeService(
+            channel="ibm_cloud",
+            token="abc",
+            instance="crn:v1:bluemix:public:quantum-computing:my-region:a/...:...::",
+        )
+        with self.assertRaises(IBMInputValueError):
+            service.instances(group="hub0")
+        with self.assertRaises(IBMInputValueError):
+            service.instance_groups()
+
+    def test_support_bundle(self):
+        """Test writing a support bundle without credentials."""
+        service = FakeRuntimeService(channel="ibm_quantum", token="abc")
+        service._api_client._session = MagicMock(
+            recent_requests=[{"path": "/jobs", "status_code": 200}],
+            last_error={"path": "/jobs/foo", "status_code": 404, "message": "Not found"},
+        )
+        with tempfile.TemporaryDirectory() as tmp_dir, patch(
+            "qiskit_ibm_runtime.qiskit_runtime_service.VersionClient"
+        ) as version_client:
+            version_client.return_value.version.return_value = {"new_api": True}
+            path = os.path.join(tmp_dir, "bundle.json")
+            service.support_bundle(path)
+            with open(path, encoding="utf-8") as bundle_file:
+                content = bundle_file.read()
+        bundle = json.loads(content)
+        self.assertNotIn("abc", content)
+        self.assertEqual(bundle["version_info"]["client_version"], __version__)
+        self.assertEqual(bundle["account"]["channel"], "ibm_quantum")
+        self.assertEqual(bundle["capabilities"]["instances"], service.instances())
+        self.assertEqual(bundle["recent_requests"], [{"path": "/jobs", "status_code": 200}])
+        self.assertEqual(bundle["last_error"]["status_code"], 404)
+
     def test_enable_account_by_name_input_instance(self):
         """Test initializing account by name and input instance."""
         name = "foo"
@@ -0,0 +1,84 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Tests for AuthClient."""
+
+from concurrent.futures import ThreadPoolExecutor
+from datetime import datetime, timezone
+from unittest.mock import MagicMock, patch
+
+from qiskit_ibm_runtime.api.auth import QuantumAuth
+from qiskit_ibm_runtime.api.client_parameters import ClientParameters
+from qiskit_ibm_runtime.api.clients.auth import _ACCESS_TOKEN_CACHE, AuthClient
+from qiskit_ibm_runtime.exceptions import IBMNotAuthorizedError
+
+from ..ibm_test_case import IBMTestCase
+
+
+class TestAuthClient(IBMTestCase):
+    """Tests for ``AuthClient``."""
+
+    def setUp(self):
+        """Initial test setup."""
+        super().setUp()
+        _ACCESS_TOKEN_CACHE.clear()
+        self.addCleanup(_ACCESS_TOKEN_CACHE.clear)
+        self.api = MagicMock()
+        self.api.user_info.return_value = {"urls": {"http": "https://dummy_api"}}
+        self.api.session.server_time.side_effect = lambda: datetime.now(timezone.utc)
+        patcher = patch("qiskit_ibm_runtime.api.clients.auth.Api", return_value=self.api)
+        patcher.start()
+        self.addCleanup(patcher.stop)
+
+    def test_shared_access_token(self):
+        """Test clients using the same API token share one access token."""
+        self.api.login.return_value = {"id": "access_token"}
+        params = ClientParameters(channel="ibm_quantum", token="key", url="https://auth")
+        with ThreadPoolExecutor(max_workers=4) as executor:
+            clients = list(executor.map(lambda _: AuthClient(params), range(4)))
+        self.assertEqual(self.api.login.call_count, 1)
+        self.assertTrue(all(client.access_token == "access_token" for client in clients))
+
+        AuthClient(ClientParameters(channel="ibm_quantum", token="other", url="https://auth"))
+        self.assertEqual(self.api.login.call_count, 2)
+
+    def test_access_token_refresh(self):
+        """Test an expiring access token is refreshed before it is handed out."""
+        self.api.login.side_effect = [
+            {"id": "expiring", "ttl": 60},
+            {"id": "fresh", "ttl": 3600},
+        ]
+        params = ClientParameters(channel="ibm_quantum", token="key", url="https://auth")
+        client = AuthClient(params)
+        self.assertEqual(client.access_token, "expiring")
+        with self.assertLogs("qiskit_ibm_runtime.api.clients.auth", level="WARNING"):
+            self.assertEqual(client.current_access_token(), "fresh")
+        self.assertEqual(self.api.session.auth, QuantumAuth(access_token="fresh"))
+        self.assertEqual(client.current_access_token(), "fresh")
+        self.assertEqual(self.api.login.call_count, 2)
+
+    def test_rejected_access_token(self):
+        """Test a rejected shared access token is evicted and a new one is requested."""
+        self.api.login.side_effect = [{"id": "revoked"}, {"id": "renewed"}]
+        self.api.user_info.side_effect = [
+            IBMNotAuthorizedError("Unauthorized"),
+            {"urls": {"http": "https://dummy_api"}},
+            {"urls": {"http": "https://dummy_api"}},
+        ]
+        params = ClientParameters(channel="ibm_quantum", token="key", url="https://auth")
+        client = AuthClient(params)
+        self.assertEqual(client.access_token, "renewed")
+        self.assertEqual(self.api.session.auth, QuantumAuth(access_token="renewed"))
+
+        # Other clients reuse the renewed token rather than the rejected one.
+        self.assertEqual(AuthClient(params).access_token, "renewed")
+        self.assertEqual(self.api.login.call_count, 2)
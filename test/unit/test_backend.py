@@ -12,6 +12,7 @@
 
 """Tests for the backend functions."""
 import copy
+from datetime import datetime
 from unittest import mock
 
 from ddt import named_data, ddt
@@ -23,11 +24,21 @@ from qiskit.providers.models import (
     BackendStatus,
     PulseDefaults,
 )
+from qiskit.providers.models.backendproperties import Nduv
 
 from qiskit_ibm_runtime import SamplerV2
-from qiskit_ibm_runtime.fake_provider import FakeManila, FakeSherbrooke, FakeFractionalBackend
+from qiskit_ibm_runtime.fake_provider import (
+    FakeBrisbane,
+    FakeFractionalBackend,
+    FakeManila,
+    FakeSherbrooke,
+    FakeTorino,
+)
 from qiskit_ibm_runtime.ibm_backend import IBMBackend
 from qiskit_ibm_runtime.utils.backend_converter import convert_to_target
+from qiskit_ibm_runtime.utils.backend_decoder import properties_from_server_data
+from qiskit_ibm_runtime.utils.converters import duration_to_seconds
+from qiskit_ibm_runtime.exceptions import IBMInputValueError
 
 from ..ibm_test_case import IBMTestCase
 from ..utils import create_faulty_backend
@@ -333,6 +344,58 @@ class TestBackend(IBMTestCase):
             faulty_backend.properties().frequency(1),
         )
 
+    def test_properties_duration_units(self):
+        """Test durations in properties are normalized to seconds."""
+        props_dict = copy.deepcopy(FakeManila()._props_dict)
+        t1_entry = next(nduv for nduv in props_dict["qubits"][0] if nduv["name"] == "T1")
+        t1_entry["value"] = 100.0
+        t1_entry["unit"] = "μs"
+        gate_entry = next(gate for gate in props_dict["gates"] if gate["qubits"] == [0])
+        length_entry = next(
+            param for param in gate_entry["parameters"] if param["name"] == "gate_length"
+        )
+        length_entry["value"] = 35.5
+        length_entry["unit"] = "ns"
+
+        properties = properties_from_server_data(props_dict)
+        raw_t1 = next(nduv for nduv in properties.qubits[0] if nduv.name == "T1")
+        self.assertEqual(raw_t1.value, 100.0)
+        self.assertEqual(raw_t1.unit, "us")
+        self.assertAlmostEqual(properties.t1(0), 100e-6)
+        self.assertAlmostEqual(properties.gate_length(gate_entry["gate"], [0]), 35.5e-9)
+
+    def test_properties_eagle_and_heron(self):
+        """Test decoding the properties of Eagle and Heron backends into typed entries."""
+        for backend in [FakeBrisbane(), FakeTorino()]:
+            with self.subTest(backend=backend.name):
+                backend._set_props_dict_from_json()
+                props_dict = copy.deepcopy(backend._props_dict)
+                raw_t1 = next(nduv for nduv in props_dict["qubits"][0] if nduv["name"] == "T1")
+                raw_gate = next(gate for gate in props_dict["gates"] if len(gate["qubits"]) == 2)
+                raw_error = next(
+                    param for param in raw_gate["parameters"] if param["name"] == "gate_error"
+                )
+
+                properties = properties_from_server_data(props_dict)
+                t1_entry = next(nduv for nduv in properties.qubits[0] if nduv.name == "T1")
+                self.assertIsInstance(t1_entry, Nduv)
+                self.assertEqual(t1_entry.unit, "us")
+                self.assertIsInstance(t1_entry.date, datetime)
+                self.assertAlmostEqual(properties.t1(0), raw_t1["value"] * 1e-6)
+                self.assertAlmostEqual(
+                    properties.gate_error(raw_gate["gate"], raw_gate["qubits"]),
+                    raw_error["value"],
+                )
+
+    def test_duration_to_seconds(self):
+        """Test converting durations to seconds."""
+        self.assertAlmostEqual(duration_to_seconds(35.5, "ns"), 35.5e-9)
+        self.assertAlmostEqual(duration_to_seconds(100, "µs"), 100e-6)
+        self.assertAlmostEqual(duration_to_seconds(100, "us"), 100e-6)
+        self.assertEqual(duration_to_seconds(2, "s"), 2)
+        with self.assertRaises(IBMInputValueError):
+            duration_to_seconds(1, "GHz")
+
     @named_data(
         ("with_dynamic_with_fractional", [True, True]),
         ("with_dynamic_without_fractional", [True, False]),
@@ -12,10 +12,15 @@
 
 """Backends Filtering Test."""
 
+import json
+import os
+import tempfile
 import uuid
+from unittest import mock
 from ddt import ddt, named_data
 
 from qiskit.providers.exceptions import QiskitBackendNotFoundError
+from qiskit_ibm_runtime.api.exceptions import RequestsApiError
 from qiskit_ibm_runtime.fake_provider import FakeLima
 
 from .mock.fake_runtime_service import FakeRuntimeService
@@ -261,6 +266,68 @@ class TestGetBackend(IBMTestCase):
         with self.assertRaises(QiskitBackendNotFoundError):
             _ = service.backend(backend_name, instance=hgp)
 
+    def test_backend_configurations(self):
+        """Test getting the configurations of all backends."""
+        service = FakeRuntimeService(channel="ibm_quantum", token="my_token")
+        configurations = service.backend_configurations()
+        self.assertEqual(sorted(configurations), sorted(back.name for back in service.backends()))
+        hgp = FakeRuntimeService.DEFAULT_HGPS[1]
+        self.assertEqual(
+            sorted(service.backend_configurations(instance=hgp)),
+            sorted(back.name for back in service.backends(instance=hgp)),
+        )
+
+    def test_backend_snapshot(self):
+        """Test getting the configuration, properties and status of a backend at once."""
+        service = FakeRuntimeService(
+            channel="ibm_quantum",
+            token="my_token",
+            backend_specs=[FakeApiBackendSpecs(backend_name="FakeManilaV2")],
+        )
+        name = service.backends()[0].name
+        snapshot = service.backend_snapshot(name)
+        self.assertEqual(snapshot["configuration"].backend_name, name)
+        self.assertEqual(snapshot["properties"].backend_name, name)
+        self.assertTrue(snapshot["status"].operational)
+
+        with self.assertRaises(QiskitBackendNotFoundError):
+            service.backend_snapshot("unknown_backend")
+
+    def test_export_fleet_snapshot(self):
+        """Test writing the state of the backends to a file."""
+        service = FakeRuntimeService(channel="ibm_quantum", token="my_token")
+        with tempfile.TemporaryDirectory() as directory:
+            path = service.export_fleet_snapshot(directory)
+            self.assertEqual(os.path.dirname(path), directory)
+            self.assertTrue(os.path.basename(path).startswith("fleet_snapshot_"))
+            with open(path, encoding="utf-8") as file:
+                archive = json.load(file)
+        self.assertIn("timestamp", archive)
+        self.assertEqual(
+            sorted(archive["backends"]), sorted(back.name for back in service.backends())
+        )
+        for snapshot in archive["backends"].values():
+            self.assertEqual(set(snapshot), {"configuration", "properties", "status"})
+
+        failing = service.backends()[0].name
+        backend_snapshot = service._api_client.backend_snapshot
+
+        def _snapshot(name):
+            if name == failing:
+                raise RequestsApiError("Internal server error", status_code=500)
+            return backend_snapshot(name)
+
+        with tempfile.TemporaryDirectory() as directory, mock.patch.object(
+            service._api_client, "backend_snapshot", side_effect=_snapshot
+        ), self.assertLogs("qiskit_ibm_runtime", level="WARNING"):
+            with open(service.export_fleet_snapshot(directory), encoding="utf-8") as file:
+                archive = json.load(file)
+        self.assertEqual(set(archive["backends"][failing]), {"error"})
+        self.assertIn("Internal server error", archive["backends"][failing]["error"])
+        for name, snapshot in archive["backends"].items():
+            if name != failing:
+                self.assertEqual(set(snapshot), {"configuration", "properties", "status"})
+
     @named_data(
         ("with_fractional", True),
         ("without_fractional", False),
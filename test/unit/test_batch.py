@@ -16,8 +16,9 @@ from unittest.mock import MagicMock
 
 from qiskit_ibm_runtime import Batch
 from qiskit_ibm_runtime.utils.default_session import _DEFAULT_SESSION
-from qiskit_ibm_runtime.exceptions import IBMRuntimeError
+from qiskit_ibm_runtime.exceptions import IBMRuntimeError, IBMInputValueError
 
+from .mock.fake_runtime_service import FakeRuntimeService
 from ..ibm_test_case import IBMTestCase
 from ..utils import get_mocked_backend
 
@@ -56,3 +57,16 @@ class TestBatch(IBMTestCase):
             session.run(program_id="foo", inputs={})
             session.cancel()
         self.assertFalse(session._active)
+
+    def test_execution_mode(self):
+        """Test batch is created with the batch execution mode."""
+        backend = get_mocked_backend(name="ibm_gotham")
+        Batch(service=backend.service, backend=backend)
+        create_session = backend.service._api_client.create_session
+        self.assertEqual(create_session.call_args.args[-1], "batch")
+
+    def test_from_id_wrong_mode(self):
+        """Test batch can not be created from the ID of a dedicated session."""
+        service = FakeRuntimeService(channel="ibm_quantum", token="abc")
+        with self.assertRaises(IBMInputValueError):
+            Batch.from_id(session_id="123", service=service)
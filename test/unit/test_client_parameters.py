@@ -13,12 +13,14 @@
 """Tests for ClientParameters."""
 
 import uuid
+from unittest.mock import patch
 
 from requests_ntlm import HttpNtlmAuth
 
 from qiskit_ibm_runtime.proxies import ProxyConfiguration
 from qiskit_ibm_runtime.api.client_parameters import ClientParameters
-from qiskit_ibm_runtime.api.auth import CloudAuth, QuantumAuth
+from qiskit_ibm_runtime.api.auth import CloudAuth, QuantumAuth, TrustedProfileAuth
+from qiskit_ibm_runtime.exceptions import IBMInputValueError
 
 from ..ibm_test_case import IBMTestCase
 
@@ -50,6 +52,30 @@ class TestClientParameters(IBMTestCase):
         result = proxies_only_credentials.connection_parameters()
         self.assertDictEqual(proxies_only_expected_result, result)
 
+    def test_hook_params(self) -> None:
+        """Test request and response hooks are passed on to the session."""
+
+        def request_hook(method, url, headers):
+            pass
+
+        def response_hook(response):
+            pass
+
+        hooks_expected_result = {
+            "verify": True,
+            "request_hooks": [request_hook],
+            "response_hooks": [response_hook],
+        }
+        hooks_credentials = ClientParameters(
+            channel="ibm_quantum",
+            token="dummy_token",
+            url="https://dummy_url",
+            request_hooks=[request_hook],
+            response_hooks=[response_hook],
+        )
+        result = hooks_credentials.connection_parameters()
+        self.assertDictEqual(hooks_expected_result, result)
+
     def test_get_runtime_api_base_url(self) -> None:
         """Test resolution of runtime API base URL."""
         test_specs = [
@@ -71,6 +97,12 @@ class TestClientParameters(IBMTestCase):
                 "https://api-ntc-name.experimental-us-someid.us-east.containers.appdomain.cloud",
                 "https://api-ntc-name.experimental-us-someid.us-east.containers.appdomain.cloud",
             ),
+            (
+                "ibm_cloud",
+                "crn:v1:bluemix:public:quantum-computing:us-east:a/...:...::",
+                "https://us-east.quantum-computing.cloud.ibm.com",
+                "https://us-east.quantum-computing.cloud.ibm.com",
+            ),
             (
                 "ibm_quantum",
                 "h/g/p",
@@ -84,6 +116,16 @@ class TestClientParameters(IBMTestCase):
                 params = self._get_client_params(channel=channel, instance=instance, url=url)
                 self.assertEqual(params.get_runtime_api_base_url(), expected)
 
+    def test_get_runtime_api_base_url_region_mismatch(self) -> None:
+        """Test a URL in another region than the CRN is rejected."""
+        params = self._get_client_params(
+            channel="ibm_cloud",
+            instance="crn:v1:bluemix:public:quantum-computing:us-east:a/...:...::",
+            url="https://eu-de.quantum-computing.cloud.ibm.com",
+        )
+        with self.assertRaisesRegex(IBMInputValueError, "eu-de"):
+            params.get_runtime_api_base_url()
+
     def test_proxies_param_with_ntlm(self) -> None:
         """Test proxies with NTLM credentials."""
         urls = {"http": "localhost:8080", "https": "localhost:8080"}
@@ -145,6 +187,38 @@ class TestClientParameters(IBMTestCase):
         self.assertIn(f"apikey {token}", handler.get_headers().values())
         self.assertIn(instance, handler.get_headers().values())
 
+    def test_auth_handler_trusted_profile(self):
+        """Test getting the trusted profile auth handler of a cloud account."""
+
+        def authenticate(request):
+            request["headers"]["Authorization"] = "Bearer iam_token"
+
+        instance = uuid.uuid4().hex
+        with patch("qiskit_ibm_runtime.utils.utils.ContainerAuthenticator") as authenticator:
+            authenticator.return_value.authenticate.side_effect = authenticate
+            for trusted_profile, profile_arg in [
+                ("Profile-0123-abcd", "iam_profile_id"),
+                ("my-profile", "iam_profile_name"),
+            ]:
+                with self.subTest(trusted_profile=trusted_profile):
+                    params = ClientParameters(
+                        channel="ibm_cloud",
+                        token=None,
+                        url="https://cloud.ibm.com",
+                        instance=instance,
+                        trusted_profile=trusted_profile,
+                    )
+                    handler = params.get_auth_handler()
+                    self.assertIsInstance(handler, TrustedProfileAuth)
+                    self.assertEqual(
+                        handler.get_headers(),
+                        {"Service-CRN": instance, "Authorization": "Bearer iam_token"},
+                    )
+                    self.assertEqual(
+                        authenticator.call_args.kwargs,
+                        {profile_arg: trusted_profile, "url": "https://iam.cloud.ibm.com"},
+                    )
+
     def _get_client_params(
         self,
         channel="ibm_quantum",
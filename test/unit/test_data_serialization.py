@@ -16,13 +16,17 @@ import json
 import os
 import subprocess
 import tempfile
+import time
 import warnings
 from datetime import datetime
+from unittest import skipIf
+from unittest.mock import patch
 
 import numpy as np
 from ddt import data, ddt
 
 from qiskit.circuit import Parameter, ParameterVector, QuantumCircuit
+from qiskit.qpy import dump
 from qiskit.circuit.library import EfficientSU2, CXGate, PhaseGate, U2Gate
 
 import qiskit.quantum_info as qi
@@ -41,6 +45,18 @@ from qiskit.primitives.containers import (
 )
 from qiskit_aer.noise import NoiseModel
 from qiskit_ibm_runtime.utils import RuntimeEncoder, RuntimeDecoder
+from qiskit_ibm_runtime.utils.payload_cache import (
+    PayloadCache,
+    enable_payload_cache,
+    disable_payload_cache,
+)
+from qiskit_ibm_runtime.utils.result_decoder import ResultDecoder, select_pub_results
+from qiskit_ibm_runtime.utils.result_export import (
+    HAS_PYARROW,
+    sampler_result_to_arrow,
+    sampler_result_to_parquet,
+)
+from qiskit_ibm_runtime.exceptions import IBMInputValueError
 from qiskit_ibm_runtime.fake_provider import FakeNairobi
 
 from .mock.fake_runtime_client import CustomResultRuntimeJob
@@ -54,6 +70,9 @@ from ..serialization import (
 )
 from ..utils import mock_wait_for_final_state, bell
 
+if HAS_PYARROW:
+    import pyarrow.parquet
+
 
 @ddt
 class TestDataSerialization(IBMTestCase):
@@ -254,6 +273,66 @@ if __name__ == '__main__':
                     result = job.result(decoder=decoder)
                 self.assertIsInstance(result["serializable_class"], SerializableClass)
 
+    def test_payload_cache(self):
+        """Test reusing the encoded payload of circuits submitted repeatedly."""
+        circuit = EfficientSU2(2, reps=1).decompose()
+        self.addCleanup(disable_payload_cache)
+        with tempfile.TemporaryDirectory() as directory:
+            cache = enable_payload_cache(directory=directory)
+            first = json.dumps(circuit, cls=RuntimeEncoder)
+            second = json.dumps(circuit.copy(), cls=RuntimeEncoder)
+            self.assertEqual(first, second)
+            self.assertEqual((cache.hits, cache.misses), (1, 1))
+            self.assertEqual(json.loads(second, cls=RuntimeDecoder), circuit)
+
+            # A new cache reuses the payloads stored on disk.
+            cache = enable_payload_cache(directory=directory)
+            self.assertEqual(json.dumps(circuit, cls=RuntimeEncoder), first)
+            self.assertEqual((cache.hits, cache.misses), (1, 0))
+
+            circuit.h(0)
+            self.assertNotEqual(json.dumps(circuit, cls=RuntimeEncoder), first)
+            self.assertEqual((cache.hits, cache.misses), (1, 1))
+
+    def test_payload_cache_serialization(self):
+        """Test circuits are only serialized again once they are modified."""
+        circuit = EfficientSU2(2, reps=1).decompose()
+        self.addCleanup(disable_payload_cache)
+        enable_payload_cache()
+        with patch("qiskit_ibm_runtime.utils.json.dump", wraps=dump) as qpy_dump:
+            first = json.dumps(circuit, cls=RuntimeEncoder)
+            self.assertEqual(json.dumps(circuit, cls=RuntimeEncoder), first)
+            self.assertEqual(qpy_dump.call_count, 1)
+
+            circuit.h(0)
+            modified = json.dumps(circuit, cls=RuntimeEncoder)
+            self.assertNotEqual(modified, first)
+            self.assertEqual(qpy_dump.call_count, 2)
+        self.assertEqual(json.loads(modified, cls=RuntimeDecoder), circuit)
+
+    def test_payload_cache_disk_limit(self):
+        """Test the least recently used payloads are removed from disk above the limit."""
+        with tempfile.TemporaryDirectory() as directory:
+            cache = PayloadCache(directory=directory, max_disk_bytes=25)
+            for index in range(3):
+                cache.encode(bytes([index]), lambda data: data.hex() * 5)
+                cache.clear()
+                # The payloads are ordered by modification time.
+                time.sleep(0.01)
+            self.assertEqual(len(os.listdir(directory)), 2)
+            cache.encode(bytes([0]), lambda data: data.hex() * 5)
+            self.assertEqual(cache.misses, 4)
+
+    def test_encoder_circuit_sizes(self):
+        """Test that the encoder reports the sizes of the circuits it encodes."""
+        circuit = EfficientSU2(2, reps=1).decompose()
+        encoder = RuntimeEncoder()
+        payload = encoder.encode({"pubs": [(circuit,), (circuit,)]})
+        self.assertEqual(encoder.num_circuits, 2)
+        self.assertGreater(encoder.circuit_bytes, 0)
+        self.assertGreater(encoder.encoded_circuit_bytes, 0)
+        self.assertLess(encoder.encoded_circuit_bytes, len(payload))
+
     def test_circuit_metadata(self):
         """Test serializing circuit metadata."""
 
@@ -539,6 +618,52 @@ class TestContainerSerialization(IBMTestCase):
             self.assertIsInstance(decoded, PrimitiveResult)
             self.assert_primitive_results_equal(primitive_result, decoded)
 
+    def test_select_pub_results(self):
+        """Test decoding the results of a subset of PUBs."""
+        for primitive_result in self.make_test_primitive_results():
+            encoded = json.dumps(primitive_result, cls=RuntimeEncoder)
+            decoded = ResultDecoder.decode(select_pub_results(encoded, [2, 0]))
+            self.assertIsInstance(decoded, PrimitiveResult)
+            self.assertEqual(len(decoded), 2)
+            self.assert_pub_results_equal(primitive_result[2], decoded[0])
+            self.assert_pub_results_equal(primitive_result[0], decoded[1])
+            self.assertEqual(primitive_result.metadata, decoded.metadata)
+
+            with self.assertRaises(IBMInputValueError):
+                select_pub_results(encoded, [len(primitive_result)])
+
+    @skipIf(not HAS_PYARROW, "pyarrow is required.")
+    def test_sampler_result_to_arrow(self):
+        """Test exporting sampler shot data to an Arrow table and a Parquet file."""
+        result = PrimitiveResult(
+            [
+                SamplerPubResult(
+                    DataBin(meas=BitArray.from_samples(["00", "11", "01"], num_bits=2), shape=())
+                ),
+                SamplerPubResult(
+                    DataBin(
+                        alpha=BitArray.from_bool_array([[[1]], [[0]]]),
+                        beta=np.zeros(2),
+                        shape=(2,),
+                    )
+                ),
+            ]
+        )
+        table = sampler_result_to_arrow(result)
+        self.assertEqual(table.num_rows, 5)
+        self.assertEqual(table.column("pub_index").to_pylist(), [0, 0, 0, 1, 1])
+        self.assertEqual(
+            table.column("register").to_pylist(), ["meas", "meas", "meas", "alpha", "alpha"]
+        )
+        self.assertEqual(table.column("position").to_pylist(), [0, 0, 0, 0, 1])
+        self.assertEqual(table.column("shot").to_pylist(), [0, 1, 2, 0, 0])
+        self.assertEqual(table.column("bitstring").to_pylist(), ["00", "11", "01", "1", "0"])
+
+        with tempfile.TemporaryDirectory() as tmp_dir:
+            path = os.path.join(tmp_dir, "result.parquet")
+            sampler_result_to_parquet(result, path)
+            self.assertTrue(pyarrow.parquet.read_table(path).equals(table))
+
     def test_unknown_settings(self):
         """Test settings not on whitelisted path."""
         random_settings = {
@@ -18,9 +18,11 @@ from ddt import data, ddt
 from qiskit import QuantumCircuit, transpile
 from qiskit.circuit.library import RealAmplitudes
 from qiskit.quantum_info import SparsePauliOp, Pauli
+from qiskit.primitives.containers import DataBin, PrimitiveResult, PubResult
 from qiskit.primitives.containers.estimator_pub import EstimatorPub
 
 from qiskit_ibm_runtime import Estimator, Session, EstimatorV2, EstimatorOptions, IBMInputValueError
+from qiskit_ibm_runtime import MitigationMetadata
 
 from .mock.fake_runtime_service import FakeRuntimeService
 from ..ibm_test_case import IBMTestCase
@@ -286,3 +288,36 @@ class TestEstimatorV2(IBMTestCase):
         obs = []
         with self.assertRaisesRegex(ValueError, "Empty observables array is not allowed"):
             inst.run(pubs=[(circ, obs)])
+
+    def test_mitigation_metadata(self):
+        """Test parsing the error mitigation metadata of a result."""
+        result = PrimitiveResult(
+            [
+                PubResult(
+                    DataBin(evs=np.array(1.0), stds=np.array(0.1)),
+                    metadata={
+                        "resilience": {
+                            "zne": {"noise_factors": [1, 3, 5], "extrapolator": "exponential"},
+                            "layer_noise": {"noise_overhead": 1.5, "total_mitigated_layers": 4},
+                        }
+                    },
+                ),
+                PubResult(DataBin(evs=np.array(0.5), stds=np.array(0.1)), metadata={}),
+            ],
+            metadata={
+                "twirling": {"enable_gates": True, "strategy": "active-accum", "new_field": 1},
+                "resilience": {"measure_mitigation": True, "zne_mitigation": True},
+                "version": 2,
+            },
+        )
+        metadata = MitigationMetadata.from_result(result)
+        self.assertTrue(metadata.twirling.enable_gates)
+        self.assertEqual(metadata.twirling.strategy, "active-accum")
+        self.assertEqual(metadata.twirling.raw["new_field"], 1)
+        self.assertIsNone(metadata.dynamical_decoupling)
+        self.assertTrue(metadata.resilience.measure_mitigation)
+        self.assertIsNone(metadata.resilience.pec_mitigation)
+        self.assertEqual(metadata.pubs[0].zne.noise_factors, [1, 3, 5])
+        self.assertEqual(metadata.pubs[0].zne.extrapolator, "exponential")
+        self.assertEqual(metadata.pubs[0].layer_noise.total_mitigated_layers, 4)
+        self.assertIsNone(metadata.pubs[1].zne)
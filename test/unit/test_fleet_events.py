@@ -0,0 +1,148 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Tests for monitoring a fleet of backends."""
+
+import asyncio
+import time
+from datetime import datetime
+from unittest import mock
+
+from qiskit_ibm_runtime.fake_provider import FakeBrisbane, FakeManila
+from qiskit_ibm_runtime.utils.backend_decoder import properties_from_server_data
+from qiskit_ibm_runtime.utils.benchmarks import fleet_benchmarks
+from qiskit_ibm_runtime.utils.fleet_events import FleetEvent, FleetMonitor
+from qiskit_ibm_runtime.utils.properties_export import (
+    properties_dataframe,
+    gate_properties_dataframe,
+)
+from qiskit_ibm_runtime.exceptions import IBMBackendValueError
+
+from ..ibm_test_case import IBMTestCase
+
+
+class TestFleetEvents(IBMTestCase):
+    """Tests for ``FleetMonitor`` and the fleet-wide backend data."""
+
+    def test_properties_dataframe(self):
+        """Test exporting backend properties as columnar arrays."""
+        properties = properties_from_server_data(FakeManila()._props_dict)
+        qubit_columns = properties_dataframe(properties)
+        self.assertEqual(list(qubit_columns["qubit"]), list(range(5)))
+        for qubit in range(5):
+            self.assertAlmostEqual(qubit_columns["t1"][qubit], properties.t1(qubit))
+            self.assertAlmostEqual(
+                qubit_columns["readout_error"][qubit], properties.readout_error(qubit)
+            )
+            self.assertAlmostEqual(
+                qubit_columns["sx_error"][qubit], properties.gate_error("sx", qubit)
+            )
+
+        gate_columns = gate_properties_dataframe(properties)
+        self.assertEqual(len(gate_columns["gate"]), len(properties.gates))
+        index = gate_columns["qubits"].index((0, 1))
+        self.assertEqual(gate_columns["gate"][index], "cx")
+        self.assertAlmostEqual(
+            gate_columns["gate_error"][index], properties.gate_error("cx", [0, 1])
+        )
+
+        backend = mock.MagicMock()
+        backend.properties.return_value = None
+        with self.assertRaises(IBMBackendValueError):
+            properties_dataframe(backend)
+
+    def test_fleet_benchmarks(self):
+        """Test reading the benchmark figures of backends."""
+        benchmarks = fleet_benchmarks([FakeBrisbane(), FakeManila()])
+        brisbane = benchmarks["fake_brisbane"]
+        self.assertEqual(brisbane.clops_h, 5000)
+        self.assertIsNone(brisbane.clops_v)
+        layer_fidelity = FakeBrisbane().properties().general
+        lf_100 = next(item.value for item in layer_fidelity if item.name == "lf_100")
+        self.assertAlmostEqual(brisbane.eplg, 1 - lf_100 ** (1 / 99))
+
+        manila = benchmarks["fake_manila"]
+        self.assertEqual(manila.quantum_volume, 32)
+        self.assertEqual(manila.clops_v, 2819)
+        self.assertIsNone(manila.eplg)
+
+    def test_fleet_monitor(self):
+        """Test detecting changes in the state of backends."""
+        backend = mock.MagicMock()
+        backend.name = "ibm_gotham"
+        backend.status.side_effect = [
+            mock.MagicMock(operational=operational, status_msg=msg, pending_jobs=pending_jobs)
+            for operational, msg, pending_jobs in [
+                (True, "active", 5),
+                (False, "maintenance", 5),
+                (True, "active", 20),
+            ]
+        ]
+        calibrations = [datetime(2024, 5, 1), datetime(2024, 5, 1), datetime(2024, 5, 2)]
+        backend.properties.side_effect = [
+            mock.MagicMock(last_update_date=date) for date in calibrations
+        ]
+        monitor = FleetMonitor([backend], queue_threshold=10)
+        self.assertEqual(monitor.poll(), [])
+
+        events = monitor.poll()
+        self.assertEqual([event.event_type for event in events], ["went_down"])
+        self.assertEqual(events[0].details, {"status_msg": "maintenance"})
+
+        events = monitor.poll()
+        self.assertEqual(
+            [event.event_type for event in events],
+            ["came_up", "calibration_updated", "queue_above_threshold"],
+        )
+        self.assertEqual(events[2].details, {"pending_jobs": 20})
+
+        with self.assertRaises(ValueError):
+            FleetMonitor([backend], interval=0)
+
+    def test_fleet_monitor_async(self):
+        """Test iterating over the events of a fleet of backends asynchronously."""
+        backend = mock.MagicMock()
+        backend.name = "ibm_gotham"
+        backend.status.side_effect = [
+            mock.MagicMock(operational=operational, status_msg="", pending_jobs=0)
+            for operational in (True, False, True)
+        ]
+        backend.properties.return_value = None
+        monitor = FleetMonitor([backend], interval=0.01)
+
+        async def collect():
+            events = []
+            async for event in monitor:
+                events.append(event.event_type)
+                if len(events) == 2:
+                    monitor.stop()
+            return events
+
+        self.assertEqual(asyncio.run(collect()), [FleetEvent.WENT_DOWN, FleetEvent.CAME_UP])
+
+    def test_fleet_monitor_async_stop(self):
+        """Test stopping the asynchronous iteration does not wait for the polling interval."""
+        backend = mock.MagicMock()
+        backend.name = "ibm_gotham"
+        backend.status.return_value = mock.MagicMock(
+            operational=True, status_msg="", pending_jobs=0
+        )
+        backend.properties.return_value = None
+        monitor = FleetMonitor([backend], interval=60)
+
+        async def collect():
+            asyncio.get_running_loop().call_later(0.05, monitor.stop)
+            return [event async for event in monitor]
+
+        start = time.monotonic()
+        self.assertEqual(asyncio.run(collect()), [])
+        self.assertLess(time.monotonic() - start, 10)
@@ -0,0 +1,51 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Tests for the heavy-hex topology of backends."""
+
+from qiskit_ibm_runtime.fake_provider import FakeManila, FakeSherbrooke, FakeTorino
+from qiskit_ibm_runtime.utils.heavy_hex import HeavyHexTopology
+from qiskit_ibm_runtime.exceptions import IBMInputValueError
+
+from ..ibm_test_case import IBMTestCase
+
+
+class TestHeavyHex(IBMTestCase):
+    """Tests for ``HeavyHexTopology``."""
+
+    def test_heavy_hex_topology(self):
+        """Test classifying heavy-hex coupling maps into rows, bridges and unit cells."""
+        topology = HeavyHexTopology.from_backend(FakeSherbrooke())
+        self.assertEqual([len(row) for row in topology.rows], [14, 15, 15, 15, 15, 15, 14])
+        self.assertEqual(len(topology.bridges), 24)
+        self.assertEqual(len(topology.cells), 18)
+        cell = topology.cell(0, 0)
+        self.assertEqual(cell.qubits, (0, 1, 2, 3, 4, 15, 22, 21, 20, 19, 18, 14))
+        self.assertEqual(cell.bridges, (14, 15))
+        self.assertEqual(topology.bridges[14], (0, 1))
+        self.assertEqual(topology.position(20), (1, 2))
+        self.assertIsNone(topology.row_of(14))
+        self.assertEqual(
+            [(cell.row, cell.column) for cell in topology.cells_of(22)], [(0, 0), (0, 1), (1, 0)]
+        )
+
+        # Bridges at the edges of the lattice are connected to a single row.
+        topology = HeavyHexTopology.from_backend(FakeTorino())
+        self.assertEqual([len(row) for row in topology.rows], [15] * 7)
+        self.assertEqual(topology.bridges[129], (6,))
+
+        with self.assertRaises(IBMInputValueError):
+            topology.position(129)
+        with self.assertRaises(IBMInputValueError):
+            topology.cell(0, 5)
+        with self.assertRaises(IBMInputValueError):
+            HeavyHexTopology(FakeManila().coupling_map.get_edges())
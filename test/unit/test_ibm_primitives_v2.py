@@ -21,6 +21,7 @@ import numpy as np
 from qiskit import transpile
 from qiskit.circuit import QuantumCircuit
 from qiskit.circuit.library import RealAmplitudes
+from qiskit.primitives import PrimitiveJob
 from qiskit.quantum_info import SparsePauliOp
 
 from qiskit_ibm_runtime import Session
@@ -28,8 +29,9 @@ from qiskit_ibm_runtime.utils.default_session import _DEFAULT_SESSION
 from qiskit_ibm_runtime import EstimatorV2, SamplerV2
 from qiskit_ibm_runtime.estimator import Estimator as IBMBaseEstimator
 from qiskit_ibm_runtime.fake_provider import FakeManila
-from qiskit_ibm_runtime.exceptions import IBMInputValueError
+from qiskit_ibm_runtime.exceptions import IBMInputValueError, RuntimeJobFailureError
 from qiskit_ibm_runtime.options.utils import Unset
+from qiskit_ibm_runtime.runtime_job_v2 import RuntimeJobV2
 
 from ..ibm_test_case import IBMTestCase
 from ..utils import (
@@ -457,6 +459,28 @@ class TestPrimitivesV2(IBMTestCase):
         for idx, shots in zip([0, 1], [100, 200]):
             self.assertEqual(kwargs_list[idx][1]["inputs"]["options"]["default_shots"], shots)
 
+    @data(EstimatorV2, SamplerV2)
+    def test_run_after(self, primitive):
+        """Test submitting a job once another job is done."""
+        backend = get_mocked_backend()
+        inst = primitive(backend=backend)
+        prerequisite = MagicMock(spec=RuntimeJobV2)
+        prerequisite.wait_for_final_state.return_value = "DONE"
+        future = inst.run_after(prerequisite, **get_primitive_inputs(inst))
+        self.assertEqual(future.result(timeout=10), backend.service.run.return_value)
+        prerequisite.wait_for_final_state.assert_called_once()
+        backend.service.run.assert_called_once()
+
+        backend.service.run.reset_mock()
+        prerequisite.wait_for_final_state.return_value = "ERROR"
+        future = inst.run_after(prerequisite, **get_primitive_inputs(inst))
+        with self.assertRaises(RuntimeJobFailureError):
+            future.result(timeout=10)
+        backend.service.run.assert_not_called()
+
+        with self.assertRaisesRegex(IBMInputValueError, "RuntimeJobV2"):
+            inst.run_after(MagicMock(spec=PrimitiveJob), **get_primitive_inputs(inst))
+
     def test_run_same_session(self):
         """Test multiple runs within a session."""
         num_runs = 5
@@ -13,6 +13,9 @@
 """Tests for runtime job retrieval."""
 
 from datetime import datetime, timedelta, timezone
+from unittest.mock import patch
+
+from qiskit_ibm_runtime.exceptions import IBMInputValueError
 from .mock.fake_runtime_service import FakeRuntimeService
 from ..ibm_test_case import IBMTestCase
 from ..decorators import run_quantum_and_cloud_fake
@@ -86,6 +89,7 @@ class TestRetrieveJobs(IBMTestCase):
             jobs.append(run_program(service, program_id))
         rjobs = service.jobs(skip=4, limit=2)
         self.assertEqual(2, len(rjobs))
+        self.assertEqual(10, rjobs.total_count)
 
     @run_quantum_and_cloud_fake
     def test_jobs_pending(self, service):
@@ -222,6 +226,50 @@ class TestRetrieveJobs(IBMTestCase):
         rjobs = service.jobs(program_id=program_id, session_id="no_test_session_id")
         self.assertFalse(rjobs)
 
+    def test_jobs_filter_by_owner(self):
+        """Test retrieving only the jobs of the current user."""
+        service = self._ibm_quantum_service
+        program_id = "sampler"
+
+        job = run_program(service=service, program_id=program_id)
+        job_2 = run_program(service=service, program_id=program_id)
+        service._api_client._get_job(job_2.job_id())._user_id = "collaborator"
+        rjobs = service.jobs(program_id=program_id)
+        self.assertEqual(2, len(rjobs))
+        rjobs = service.jobs(program_id=program_id, mine_only=True)
+        self.assertEqual([job.job_id()], [rjob.job_id() for rjob in rjobs])
+        self.assertEqual("fake_user", rjobs[0].owner)
+        self.assertIsNone(rjobs.total_count)
+        # The total count is also unknown when the jobs are fetched in date windows.
+        rjobs = service.jobs(
+            limit=None,
+            mine_only=True,
+            created_after=datetime.now(timezone.utc) - timedelta(days=100),
+        )
+        self.assertEqual([job.job_id()], [rjob.job_id() for rjob in rjobs])
+        self.assertIsNone(rjobs.total_count)
+        self.assertEqual("collaborator", service.job(job_2.job_id()).owner)
+
+        # A missing owner is not fetched again.
+        service._api_client._get_job(job_2.job_id())._user_id = None
+        job_2 = service.job(job_2.job_id())
+        with patch.object(
+            service._api_client, "job_get", wraps=service._api_client.job_get
+        ) as job_get:
+            self.assertIsNone(job_2.owner)
+            self.assertIsNone(job_2.owner)
+        job_get.assert_called_once()
+
+    def test_jobs_mine_only_cloud(self):
+        """Test mine_only is rejected on the cloud channel."""
+        service = FakeRuntimeService(
+            channel="ibm_cloud",
+            token="my_token",
+            instance="crn:v1:bluemix:public:quantum-computing:my-region:a/...:...::",
+        )
+        with self.assertRaises(IBMInputValueError):
+            service.jobs(mine_only=True)
+
     def test_jobs_filter_by_date(self):
         """Test retrieving jobs filtered by date."""
         service = self._ibm_quantum_service
@@ -14,12 +14,14 @@
 
 import random
 import time
+from datetime import datetime, timedelta, timezone
 from unittest.mock import MagicMock, patch
 
 from qiskit.providers.exceptions import QiskitBackendNotFoundError
 from qiskit.providers.jobstatus import JobStatus
 
 from qiskit_ibm_runtime import RuntimeJob
+from qiskit_ibm_runtime.api.exceptions import RequestsApiError
 from qiskit_ibm_runtime.constants import API_TO_JOB_ERROR_MESSAGE
 from qiskit_ibm_runtime.exceptions import (
     RuntimeJobFailureError,
@@ -27,6 +29,7 @@ from qiskit_ibm_runtime.exceptions import (
     RuntimeJobMaxTimeoutError,
     IBMInputValueError,
     RuntimeInvalidStateError,
+    RuntimeJobTimeoutError,
 )
 from .mock.fake_runtime_client import (
     FailedRuntimeJob,
@@ -224,6 +227,56 @@ class TestRuntimeJob(IBMTestCase):
             job.wait_for_final_state()
         self.assertEqual(JobStatus.DONE, job.status())
 
+    @run_quantum_and_cloud_fake
+    def test_wait_for_final_state_backoff(self, service):
+        """Test polling for the final state with an exponential backoff."""
+        job = run_program(service)
+        statuses = [JobStatus.RUNNING] * 5 + [JobStatus.DONE]
+        with patch.object(job, "_start_websocket_client"), patch.object(
+            job, "status", side_effect=statuses
+        ), patch("qiskit_ibm_runtime.base_runtime_job.time.sleep") as mock_sleep:
+            status = job.wait_for_final_state(poll_interval=1)
+        self.assertEqual(status, JobStatus.DONE)
+        self.assertEqual([call.args[0] for call in mock_sleep.call_args_list], [1, 2, 4, 5, 5])
+
+        # An interval longer than the maximum is not shortened.
+        with patch.object(job, "_start_websocket_client"), patch.object(
+            job, "status", side_effect=[JobStatus.RUNNING] * 2 + [JobStatus.DONE]
+        ), patch("qiskit_ibm_runtime.base_runtime_job.time.sleep") as mock_sleep:
+            job.wait_for_final_state(poll_interval=7)
+        self.assertEqual([call.args[0] for call in mock_sleep.call_args_list], [7, 7])
+
+    @run_quantum_and_cloud_fake
+    def test_wait_for_final_state_metrics(self, service):
+        """Test returning the final metrics of a job along with its final status."""
+        job = run_program(service)
+        metrics = {"timestamps": {"finished": "2024-06-01T00:00:00Z"}}
+        with patch.object(job, "_start_websocket_client"), patch.object(
+            job, "status", return_value=JobStatus.DONE
+        ), patch.object(job, "metrics", return_value=metrics):
+            self.assertEqual(
+                job.wait_for_final_state(return_metrics=True), (JobStatus.DONE, metrics)
+            )
+
+    @run_quantum_and_cloud_fake
+    def test_wait_for_final_state_timeout(self, service):
+        """Test the timeout of waiting for the final state is measured with a monotonic clock."""
+        job = run_program(service)
+        clock = [0.0]
+        fake_time = MagicMock()
+        fake_time.monotonic.side_effect = lambda: clock[0]
+        fake_time.sleep.side_effect = lambda seconds: clock.__setitem__(0, clock[0] + seconds)
+        with patch.object(job, "_start_websocket_client"), patch.object(
+            job, "status", return_value=JobStatus.RUNNING
+        ), patch("qiskit_ibm_runtime.runtime_job.time", fake_time), patch(
+            "qiskit_ibm_runtime.base_runtime_job.time", fake_time
+        ):
+            with self.assertRaises(RuntimeJobTimeoutError) as err:
+                job.wait_for_final_state(timeout=10, poll_interval=1)
+        sleeps = [call.args[0] for call in fake_time.sleep.call_args_list]
+        self.assertEqual(sleeps, [1, 2, 4, 3])
+        self.assertIn("Last status", str(err.exception))
+
     @run_quantum_and_cloud_fake
     def test_delete_job(self, service):
         """Test deleting a job."""
@@ -234,6 +287,149 @@ class TestRuntimeJob(IBMTestCase):
         with self.assertRaises(RuntimeJobNotFound):
             service.job(job.job_id())
 
+    @run_quantum_and_cloud_fake
+    def test_delete_jobs(self, service):
+        """Test deleting the jobs matching filters, with a dry run first."""
+        jobs = [
+            run_program(service=service, program_id="sampler", final_status=status)
+            for status in ["COMPLETED", "CANCELLED", "RUNNING"]
+        ]
+        finished = sorted(job.job_id() for job in jobs[:2])
+
+        report = service.delete_jobs({"program_id": "sampler"})
+        self.assertEqual(sorted(report["matched"]), finished)
+        self.assertEqual(report["deleted"], [])
+        self.assertEqual(len(service.jobs(limit=None)), 3)
+
+        report = service.delete_jobs({"program_id": "sampler"}, dry_run=False)
+        self.assertEqual(sorted(report["deleted"]), finished)
+        self.assertEqual(report["failed"], {})
+        self.assertEqual([job.job_id() for job in service.jobs(limit=None)], [jobs[2].job_id()])
+
+        with self.assertRaises(IBMInputValueError):
+            service.delete_jobs({"pending": True})
+
+    @run_quantum_and_cloud_fake
+    def test_delete_jobs_failure(self, service):
+        """Test a failed deletion is reported without stopping the others."""
+        jobs = [run_program(service=service, final_status="COMPLETED") for _ in range(3)]
+        failing_id = jobs[1].job_id()
+        delete_job = service.delete_job
+
+        def _delete_job(job_id):
+            if job_id == failing_id:
+                raise RequestsApiError("Connection reset")
+            delete_job(job_id)
+
+        with patch.object(service, "delete_job", side_effect=_delete_job):
+            report = service.delete_jobs(dry_run=False)
+        self.assertCountEqual(report["deleted"], [jobs[0].job_id(), jobs[2].job_id()])
+        self.assertEqual(list(report["failed"]), [failing_id])
+        self.assertIn("Connection reset", report["failed"][failing_id])
+
+    @run_quantum_and_cloud_fake
+    def test_jobs_long_date_range(self, service):
+        """Test jobs over a long date range are fetched in concurrent windows."""
+        now = datetime.now(timezone.utc)
+        job_ids = []
+        for days in (90, 60, 30, 10, 1):
+            job = run_program(service=service, final_status="COMPLETED")
+            service._api_client._get_job(job.job_id())._creation_date = now - timedelta(days=days)
+            job_ids.append(job.job_id())
+        run_program(service=service, final_status="COMPLETED")
+
+        with patch.object(
+            service._api_client, "jobs_get", wraps=service._api_client.jobs_get
+        ) as jobs_get:
+            jobs = service.jobs(
+                limit=None,
+                created_after=now - timedelta(days=100),
+                created_before=now - timedelta(hours=1),
+            )
+        self.assertEqual(jobs_get.call_count, 4)
+        self.assertCountEqual([job.job_id() for job in jobs], job_ids)
+        self.assertEqual(jobs.total_count, len(job_ids))
+
+    @run_quantum_and_cloud_fake
+    def test_usage_by_collaborator(self, service):
+        """Test aggregating the usage of the jobs of an instance per user."""
+        usages = [("alice", 10), ("bob", 30), ("alice", 15), ("bob", None)]
+        for user_id, quantum_seconds in usages:
+            job = run_program(service=service, final_status="COMPLETED")
+            fake_job = service._api_client._get_job(job.job_id())
+            fake_job._user_id = user_id
+            if quantum_seconds is not None:
+                fake_job._usage = {"quantum_seconds": quantum_seconds}
+
+        with patch("qiskit_ibm_runtime.qiskit_runtime_service.USAGE_PAGE_SIZE", 3):
+            report = service.usage_by_collaborator()
+        self.assertEqual(
+            report,
+            [
+                {"user_id": "bob", "jobs": 2, "quantum_seconds": 30},
+                {"user_id": "alice", "jobs": 2, "quantum_seconds": 25},
+            ],
+        )
+
+    @run_quantum_and_cloud_fake
+    def test_forecast_usage(self, service):
+        """Test projecting the usage of an instance from the usage of its recent jobs."""
+        if service.channel == "ibm_quantum":
+            with self.assertRaises(IBMInputValueError):
+                service.forecast_usage()
+            return
+
+        for quantum_seconds in (40, 30):
+            job = run_program(service=service, final_status="COMPLETED")
+            service._api_client._get_job(job.job_id())._usage = {
+                "quantum_seconds": quantum_seconds
+            }
+        now = datetime.now(timezone.utc)
+        usage = {
+            "usage_consumed_seconds": 400,
+            "usage_limit_seconds": 450,
+            "usage_period": {
+                "start_time": (now - timedelta(days=20)).isoformat(),
+                "end_time": (now + timedelta(days=10)).isoformat(),
+            },
+        }
+        with patch.object(service._api_client, "usage", create=True, return_value=usage):
+            forecast = service.forecast_usage(history_days=7)
+            with self.assertRaises(IBMInputValueError):
+                service.forecast_usage(history_days=0)
+        # 70 seconds over the last 7 days, rather than 400 over the 20 days of the period.
+        self.assertAlmostEqual(forecast.daily_rate_seconds, 10, places=3)
+        self.assertAlmostEqual(
+            (forecast.projected_exhaustion - now).total_seconds(),
+            timedelta(days=5).total_seconds(),
+            delta=60,
+        )
+
+    @run_quantum_and_cloud_fake
+    def test_check_results_available(self, service):
+        """Test checking whether job results are still available."""
+        job = run_program(service=service)
+        with mock_wait_for_final_state(service, job):
+            job.wait_for_final_state()
+        fake_job = service._api_client._get_job(job.job_id())
+        self.assertTrue(service.check_results_available(job.job_id()))
+        self.assertIsNone(job.results_expiration)
+
+        expiration = datetime.now(timezone.utc) + timedelta(days=2)
+        fake_job._results_expiration = expiration.isoformat()
+        with self.assertWarns(UserWarning):
+            self.assertTrue(service.check_results_available(job.job_id()))
+        # A missing expiration date is not fetched again.
+        self.assertIsNone(job.results_expiration)
+        job = service.job(job.job_id())
+        self.assertEqual(job.results_expiration, expiration.astimezone())
+
+        fake_job._results_expiration = (expiration - timedelta(days=3)).isoformat()
+        self.assertFalse(service.check_results_available(job.job_id()))
+
+        with self.assertRaises(RuntimeJobNotFound):
+            service.check_results_available("does_not_exist")
+
     @run_quantum_and_cloud_fake
     def test_download_external_job_result(self, service):
         """Test downloading the job result from an external URL."""
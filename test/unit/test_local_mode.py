@@ -13,6 +13,7 @@
 """Tests for local mode."""
 
 import warnings
+from unittest.mock import patch
 
 from ddt import data, ddt
 
@@ -25,8 +26,11 @@ from qiskit.primitives import (
     SamplerPubResult,
 )
 from qiskit.primitives.containers.data_bin import DataBin
+from qiskit.exceptions import MissingOptionalLibraryError
+from qiskit.providers.exceptions import QiskitBackendNotFoundError
 
 from qiskit_ibm_runtime.fake_provider import FakeManila, FakeManilaV2
+from qiskit_ibm_runtime.fake_provider.local_service import QiskitRuntimeLocalService
 from qiskit_ibm_runtime import (
     Sampler,
     Estimator,
@@ -36,7 +40,9 @@ from qiskit_ibm_runtime import (
     SamplerV2,
     EstimatorV2,
 )
+from qiskit_ibm_runtime.exceptions import IBMInputValueError
 
+from .mock.fake_runtime_service import FakeRuntimeService
 from ..ibm_test_case import IBMTestCase
 from ..utils import (
     get_primitive_inputs,
@@ -235,6 +241,30 @@ class TestLocalModeV2(IBMTestCase):
                 self.assertIsInstance(pub_result.data, DataBin)
                 self.assertIsInstance(pub_result.metadata, dict)
 
+    @combine(session_cls=[Session, Batch], backend_name=["fake_manila", "aer_simulator"])
+    def test_session_local_backend_name(self, session_cls, backend_name):
+        """Test sessions on local backend names are created without an API call."""
+        with session_cls(backend=backend_name) as session:
+            self.assertIsInstance(session.service, QiskitRuntimeLocalService)
+            self.assertIsNone(session.session_id)
+            self.assertEqual(session.backend(), backend_name)
+            inst = SamplerV2(session=session)
+            job = inst.run(**get_primitive_inputs(inst, backend=session._backend))
+            self.assertIsInstance(job.result(), PrimitiveResult)
+
+    def test_session_invalid_local_backend_name(self):
+        """Test sessions on local backend names that cannot be used."""
+        with self.assertRaises(QiskitBackendNotFoundError):
+            Session(backend="fake_unknown")
+        service = FakeRuntimeService(channel="ibm_quantum", token="my_token")
+        with self.assertRaises(IBMInputValueError):
+            Session(service=service, backend="fake_manila")
+        missing_aer = MissingOptionalLibraryError("qiskit-aer", "aer_simulator", "qiskit-aer")
+        with patch("qiskit_ibm_runtime.fake_provider.local_service.optionals") as optionals:
+            optionals.HAS_AER.require_now.side_effect = missing_aer
+            with self.assertRaises(MissingOptionalLibraryError):
+                Session(backend="aer_simulator")
+
     @data(FakeManila(), FakeManilaV2(), AerSimulator())
     def test_non_primitive(self, backend):
         """Test calling non-primitive in local mode."""
@@ -0,0 +1,71 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Tests for the guidance of authentication and authorization errors."""
+
+from unittest.mock import patch
+
+from requests import Response
+
+from qiskit_ibm_runtime.api.auth import CloudAuth, QuantumAuth
+from qiskit_ibm_runtime.api.exceptions import ForbiddenApiError
+from qiskit_ibm_runtime.api.permissions import ERROR_CODES_URL, forbidden_guidance
+from qiskit_ibm_runtime.api.session import RetrySession
+from qiskit_ibm_runtime.exceptions import IBMNotAuthorizedError
+
+from ..ibm_test_case import IBMTestCase
+
+
+def _fake_response(status_code: int, content: bytes = b"{}") -> Response:
+    """Return a bare response with the given status code and content."""
+    response = Response()
+    response.status_code = status_code
+    response._content = content
+    return response
+
+
+class TestPermissions(IBMTestCase):
+    """Tests for the authorization error guidance."""
+
+    def test_authorization_errors(self):
+        """Test 401 and 403 responses raise distinct errors with guidance."""
+        session = RetrySession("https://dummy_url")
+        with patch("requests.Session.request", return_value=_fake_response(401)):
+            with self.assertRaisesRegex(IBMNotAuthorizedError, "credentials were rejected"):
+                session.post("/jobs")
+
+        forbidden = _fake_response(403, b'{"error": {"message": "Forbidden", "code": 1217}}')
+        with patch("requests.Session.request", return_value=forbidden):
+            with self.assertRaises(ForbiddenApiError) as context_manager:
+                session.post("/jobs")
+        self.assertEqual(context_manager.exception.status_code, 403)
+        self.assertEqual(context_manager.exception.error_code, "1217")
+        self.assertIn(ERROR_CODES_URL, str(context_manager.exception))
+
+    def test_forbidden_guidance_by_channel(self):
+        """Test the guidance for 403 responses only covers the channel of the account."""
+        for auth, expected, unexpected in [
+            (CloudAuth(api_key="key", crn="crn"), "IAM role", "hub/group/project"),
+            (QuantumAuth(access_token="token"), "hub/group/project", "IAM role"),
+        ]:
+            with self.subTest(auth=type(auth).__name__):
+                session = RetrySession("https://dummy_url", auth=auth)
+                with patch("requests.Session.request", return_value=_fake_response(403)):
+                    with self.assertRaises(ForbiddenApiError) as context_manager:
+                        session.get("/backends")
+                self.assertIn(expected, str(context_manager.exception))
+                self.assertNotIn(unexpected, str(context_manager.exception))
+                self.assertIsNone(context_manager.exception.error_code)
+
+        guidance = forbidden_guidance(None)
+        self.assertIn("On IBM Cloud", guidance)
+        self.assertIn("On IBM Quantum", guidance)
@@ -0,0 +1,46 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Tests for the history of backend queue lengths."""
+
+from datetime import datetime, timedelta, timezone
+from unittest import mock
+
+from qiskit_ibm_runtime.utils.queue_history import QueueLengthSampler
+
+from ..ibm_test_case import IBMTestCase
+
+
+class TestQueueHistory(IBMTestCase):
+    """Tests for ``QueueLengthSampler``."""
+
+    def test_queue_length_sampler(self):
+        """Test recording the queue length history of backends."""
+        backend = mock.MagicMock()
+        backend.name = "ibm_gotham"
+        backend.status.side_effect = [
+            mock.MagicMock(pending_jobs=5),
+            mock.MagicMock(pending_jobs=7),
+            ConnectionError("Connection reset"),
+            mock.MagicMock(pending_jobs=4),
+        ]
+        sampler = QueueLengthSampler([backend], max_samples=2)
+        for _ in range(4):
+            sampler.record()
+        self.assertEqual([count for _, count in sampler.series("ibm_gotham")], [7, 4])
+        with self.assertRaises(ValueError):
+            sampler.series("ibm_unknown")
+
+        now = datetime.now(timezone.utc)
+        sampler._history["ibm_gotham"].clear()
+        sampler._history["ibm_gotham"].extend([(now - timedelta(hours=2), 10), (now, 4)])
+        self.assertEqual(sampler.trend("ibm_gotham"), -3)
@@ -0,0 +1,46 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Tests for the readout error mitigation of counts."""
+
+import numpy as np
+
+from qiskit_ibm_runtime.fake_provider import FakeBrisbane
+from qiskit_ibm_runtime.utils.backend_decoder import properties_from_server_data
+from qiskit_ibm_runtime.utils.readout_mitigation import assignment_matrices, mitigate_counts
+from qiskit_ibm_runtime.exceptions import IBMInputValueError
+
+from ..ibm_test_case import IBMTestCase
+
+
+class TestReadoutMitigation(IBMTestCase):
+    """Tests for ``mitigate_counts``."""
+
+    def test_mitigate_counts(self):
+        """Test readout error mitigation of counts with backend calibration data."""
+        properties = properties_from_server_data(FakeBrisbane()._props_dict)
+        qubits = [3, 7]
+        matrix_3, matrix_7 = assignment_matrices(properties, qubits)
+        self.assertAlmostEqual(matrix_3[1, 0], properties.qubit_property(3)["prob_meas1_prep0"][0])
+
+        # Bell state counts, distorted by the readout errors of the qubits.
+        ideal = np.array([0.5, 0, 0, 0.5])
+        noisy = np.kron(matrix_7, matrix_3) @ ideal * 10000
+        counts = {format(index, "02b"): count for index, count in enumerate(noisy)}
+        mitigated = mitigate_counts(counts, properties, qubits)
+        for bitstring, expected in zip(["00", "01", "10", "11"], ideal):
+            self.assertAlmostEqual(mitigated.get(bitstring, 0), expected)
+
+        with self.assertRaises(IBMInputValueError):
+            mitigate_counts({"0": 10}, properties, qubits)
+        with self.assertRaises(IBMInputValueError):
+            mitigate_counts({"0" * 21: 10}, properties, list(range(21)))
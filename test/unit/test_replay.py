@@ -0,0 +1,51 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Tests for recording and replaying API exchanges."""
+
+import os
+import tempfile
+
+from qiskit_ibm_runtime.api.exceptions import RequestsApiError
+from qiskit_ibm_runtime.api.replay import ExchangeRecorder, load_exchange, replay_exchange
+from qiskit_ibm_runtime.api.session import RetrySession
+
+from .mock.http_server import SimpleServer, ClientErrorHandler
+from ..ibm_test_case import IBMTestCase
+
+
+class TestReplay(IBMTestCase):
+    """Tests for ``ExchangeRecorder`` and the replay of saved exchanges."""
+
+    def test_exchange_recorder(self):
+        """Test failed exchanges are saved without credentials and replayed offline."""
+
+        class _ErrorHandler(ClientErrorHandler):
+            error_response = {"error": {"message": "invalid params"}, "access_token": "secret"}
+
+        server = SimpleServer(handler_class=_ErrorHandler)
+        server.start()
+        self.addCleanup(server.stop)
+        with tempfile.TemporaryDirectory() as directory:
+            session = RetrySession(SimpleServer.URL, response_hooks=[ExchangeRecorder(directory)])
+            session.headers["Authorization"] = "Bearer secret"
+            with self.assertRaises(RequestsApiError):
+                session.post("/jobs", json={"program_id": "sampler", "apiToken": "secret"})
+
+            [file_name] = os.listdir(directory)
+            path = os.path.join(directory, file_name)
+            with open(path, encoding="utf-8") as file:
+                self.assertNotIn("secret", file.read())
+            exchange = load_exchange(path)
+            self.assertEqual(exchange["request"]["path"], "/jobs")
+            self.assertEqual(exchange["response"]["status_code"], 400)
+            self.assertEqual(replay_exchange(path)["error"], {"message": "invalid params"})
@@ -0,0 +1,313 @@
+# This code is part of Qiskit.
+#
+# (C) Copyright IBM 2024.
+#
+# This code is licensed under the Apache License, Version 2.0. You may
+# obtain a copy of this license in the LICENSE.txt file in the root directory
+# of this source tree or at http://www.apache.org/licenses/LICENSE-2.0.
+#
+# Any modifications or derivative works of this code must retain this
+# copyright notice, and modified files need to carry a notice indicating
+# that they have been altered from the originals.
+
+"""Tests for RetrySession."""
+
+import base64
+import gzip
+import json
+import os
+import threading
+from concurrent.futures import ThreadPoolExecutor
+from datetime import datetime, timedelta, timezone
+from email.utils import format_datetime
+from unittest.mock import patch
+
+from requests import Response
+
+from qiskit_ibm_runtime.api.clients.auth import AuthClient
+from qiskit_ibm_runtime.api.session import (
+    CONTENT_SNIPPET_LENGTH,
+    PROXY_ENV_OPT_OUT_ENV_VAR,
+    RetrySession,
+    _InFlightRequest,
+    _resolve_addresses,
+)
+from qiskit_ibm_runtime.api.exceptions import (
+    RequestsApiError,
+    UnexpectedContentTypeError,
+)
+
+from .mock.http_server import SimpleServer, BaseHandler
+from ..account import custom_envs
+from ..ibm_test_case import IBMTestCase
+
+
+def _fake_response(status_code: int = 200) -> Response:
+    """Return a bare response with the given status code."""
+    response = Response()
+    response.status_code = status_code
+    response._content = b"{}"
+    return response
+
+
+class TestRetrySession(IBMTestCase):
+    """Tests for ``RetrySession``."""
+
+    def test_request_hooks(self):
+        """Test request hooks can add headers to every request."""
+        calls = []
+
+        def _add_header(method, url, headers):
+            calls.append((method, url))
+            headers["X-Custom-Header"] = "custom"
+
+        session = RetrySession("https://dummy_url", request_hooks=[_add_header])
+        with patch("requests.Session.request", return_value=_fake_response()) as mock_request:
+            session.get("/jobs")
+
+        self.assertEqual(calls, [("GET", "https://dummy_url/jobs")])
+        self.assertEqual(mock_request.call_args.kwargs["headers"]["X-Custom-Header"], "custom")
+        self.assertNotIn("X-Custom-Header", session.headers)
+
+    def test_response_hooks(self):
+        """Test response hooks see every response, including failed ones."""
+        status_codes = []
+        session = RetrySession(
+            "https://dummy_url",
+            response_hooks=[lambda response: status_codes.append(response.status_code)],
+        )
+        with patch("requests.Session.request", side_effect=[_fake_response(), _fake_response(404)]):
+            session.get("/jobs")
+            with self.assertRaises(RequestsApiError):
+                session.get("/jobs")
+
+        self.assertEqual(status_codes, [200, 404])
+
+    def test_concurrent_gets_coalesced(self):
+        """Test identical concurrent GET requests are only sent once."""
+        release = threading.Event()
+        waiting = threading.Event()
+        original_result = _InFlightRequest.result
+
+        def _slow_request(*args, **kwargs):
+            release.wait(5)
+            return _fake_response()
+
+        def _result(in_flight):
+            waiting.set()
+            return original_result(in_flight)
+
+        session = RetrySession("https://dummy_url")
+        with patch("requests.Session.request", side_effect=_slow_request) as mock_request, patch(
+            "qiskit_ibm_runtime.api.session._InFlightRequest.result", _result
+        ):
+            with ThreadPoolExecutor(max_workers=2) as executor:
+                first = executor.submit(session.get, "/backends/foo/configuration")
+                second = executor.submit(session.get, "/backends/foo/configuration")
+                waiting.wait(5)
+                release.set()
+                responses = [first.result(), second.result()]
+            session.get("/backends/foo/configuration")
+
+        self.assertIs(responses[0], responses[1])
+        self.assertEqual(mock_request.call_count, 2)
+
+    def test_request_history(self):
+        """Test recent requests and the last error are recorded without query strings."""
+        response = _fake_response()
+        response.headers["x-request-id"] = "request-1"
+        error_response = _fake_response(404)
+        error_response.url = "https://dummy_url/jobs/foo?access_token=secret"
+        session = RetrySession("https://dummy_url")
+        with patch("requests.Session.request", side_effect=[response, error_response]):
+            session.get("/jobs", params={"access_token": "secret"})
+            with self.assertRaises(RequestsApiError):
+                session.get("/jobs/foo")
+
+        self.assertEqual(
+            [(entry["path"], entry["status_code"]) for entry in session.recent_requests],
+            [("/jobs", 200), ("/jobs/foo", 404)],
+        )
+        self.assertEqual(session.recent_requests[0]["request_id"], "request-1")
+        self.assertEqual(session.last_error["status_code"], 404)
+        self.assertNotIn("secret", session.last_error["message"])
+
+    def test_unexpected_content_type(self):
+        """Test HTML responses raise an error with the beginning of the body."""
+        page = "<html><body>" + "Service unavailable. " * 50 + "</body></html>"
+        session = RetrySession("https://dummy_url")
+        for status_code in (200, 404):
+            with self.subTest(status_code=status_code):
+                response = _fake_response(status_code)
+                response.headers["Content-Type"] = "text/html; charset=utf-8"
+                response._content = page.encode()
+                with patch("requests.Session.request", return_value=response):
+                    with self.assertRaises(UnexpectedContentTypeError) as context_manager:
+                        session.get("/jobs")
+                message = str(context_manager.exception)
+                self.assertEqual(context_manager.exception.status_code, status_code)
+                self.assertIn("Unexpected text/html; charset=utf-8 response", message)
+                self.assertIn(page[:CONTENT_SNIPPET_LENGTH], message)
+                self.assertNotIn(page, message)
+
+    def test_clock_skew(self):
+        """Test the clock skew is computed from the Date header and used for the token TTL."""
+        server_now = datetime.now(timezone.utc) + timedelta(hours=1)
+        response = _fake_response()
+        response.headers["Date"] = format_datetime(server_now, usegmt=True)
+        session = RetrySession("https://dummy_url")
+        self.assertIsNone(session.clock_skew)
+        with patch("requests.Session.request", return_value=response):
+            session.get("/version")
+        self.assertAlmostEqual(session.clock_skew, 3600, delta=5)
+        self.assertAlmostEqual((session.server_time() - server_now).total_seconds(), 0, delta=5)
+
+        client = AuthClient.__new__(AuthClient)
+        client.auth_api = type("FakeApi", (), {"session": session})()
+        client._access_token_expiry = client._token_expiry(
+            {"id": "token", "ttl": 600, "created": server_now.isoformat()}
+        )
+        # Against the local clock the token would have 4200 seconds left.
+        self.assertAlmostEqual(client.access_token_ttl(), 600, delta=5)
+        self.assertFalse(client.access_token_expiring(margin=300))
+        with self.assertLogs("qiskit_ibm_runtime.api.clients.auth", level="WARNING"):
+            self.assertTrue(client.access_token_expiring(margin=900))
+
+    def test_last_response_headers(self):
+        """Test the allow-listed headers of the last response are exposed."""
+        response = _fake_response()
+        response.headers.update(
+            {"X-RateLimit-Remaining": "12", "X-Request-Id": "abc", "Set-Cookie": "secret"}
+        )
+        session = RetrySession("https://dummy_url")
+        self.assertEqual(session.last_response_headers, {})
+        with patch("requests.Session.request", return_value=response):
+            session.get("/jobs")
+        self.assertEqual(
+            session.last_response_headers, {"x-ratelimit-remaining": "12", "x-request-id": "abc"}
+        )
+
+        session = RetrySession("https://dummy_url", exposed_headers=["Set-Cookie"])
+        with patch("requests.Session.request", return_value=response):
+            session.get("/jobs")
+        self.assertEqual(session.last_response_headers, {"set-cookie": "secret"})
+
+    def test_rate_limit_pacing(self):
+        """Test requests are delayed when the rate limit is nearly exhausted."""
+        for remaining, expected_delay in (("100", None), ("1", 15), ("0", 30)):
+            with self.subTest(remaining=remaining):
+                session = RetrySession("https://dummy_url")
+                response = _fake_response()
+                response.headers.update(
+                    {"X-RateLimit-Remaining": remaining, "X-RateLimit-Reset": "30"}
+                )
+                with patch("requests.Session.request", return_value=response):
+                    session.get("/jobs")
+                    with patch("qiskit_ibm_runtime.api.session.time.sleep") as sleep:
+                        session.get("/jobs")
+                if expected_delay is None:
+                    sleep.assert_not_called()
+                else:
+                    self.assertAlmostEqual(sleep.call_args[0][0], expected_delay, delta=1)
+
+        # Every concurrent request is counted against the remaining requests.
+        session._rate_limit = (200, session._rate_limit[1])
+        with ThreadPoolExecutor(max_workers=8) as executor:
+            for _ in range(100):
+                executor.submit(session._pace_rate_limit)
+        self.assertEqual(session._rate_limit[0], 100)
+
+    def test_request_compression(self):
+        """Test request bodies above the compression threshold are sent with gzip."""
+        payload = {"params": "quantum circuit " * 60}
+        random_data = os.urandom(1000)
+        encoded_circuits = {"params": base64.b64encode(os.urandom(3000)).decode()}
+        subtests = [
+            ("no threshold", None, {"json": payload}, False),
+            ("below threshold", 2000, {"json": payload}, False),
+            ("json", 100, {"json": payload}, True),
+            ("data", 100, {"data": json.dumps(payload)}, True),
+            ("incompressible", 100, {"data": random_data}, False),
+            ("encoded circuits", 100, {"json": encoded_circuits}, False),
+        ]
+        for name, threshold, body, compressed in subtests:
+            with self.subTest(name):
+                session = RetrySession("https://dummy_url", compression_threshold=threshold)
+                with patch("requests.Session.request", return_value=_fake_response()) as request:
+                    session.post("/jobs", **body)
+                kwargs = request.call_args.kwargs
+                if compressed:
+                    self.assertEqual(kwargs["headers"]["Content-Encoding"], "gzip")
+                    self.assertEqual(json.loads(gzip.decompress(kwargs["data"])), payload)
+                    self.assertIsNone(kwargs.get("json"))
+                else:
+                    self.assertNotIn("Content-Encoding", kwargs["headers"])
+                    sent = kwargs.get("json") or kwargs.get("data")
+                    self.assertEqual(sent, next(iter(body.values())))
+
+        with self.assertRaises(ValueError):
+            RetrySession("https://dummy_url", compression_threshold=-1)
+
+    def test_custom_resolver(self):
+        """Test connecting with a custom resolver and IP version preference."""
+        self.assertEqual(
+            _resolve_addresses("host", 443, "ipv4", lambda host: ["::1", "127.0.0.1"]),
+            ["127.0.0.1", "::1"],
+        )
+        self.assertEqual(
+            _resolve_addresses("host", 443, "ipv6", lambda host: ["127.0.0.1", "::1"]),
+            ["::1", "127.0.0.1"],
+        )
+        with self.assertRaises(ValueError):
+            RetrySession("https://dummy_url", ip_version="ipv5")
+
+        server = SimpleServer(handler_class=BaseHandler)
+        server.start()
+        self.addCleanup(server.stop)
+        resolved = []
+
+        def _resolver(host):
+            resolved.append(host)
+            return [SimpleServer.IP_ADDRESS]
+
+        url = f"http://api.quantum.invalid:{SimpleServer.PORT}"
+        session = RetrySession(url, resolver=_resolver)
+        response = session.get("/version")
+        self.assertEqual(response.status_code, 200)
+        self.assertEqual(resolved, ["api.quantum.invalid"])
+
+    def test_request_timings(self):
+        """Test the connection and response phases of requests are timed."""
+        server = SimpleServer(handler_class=BaseHandler)
+        server.start()
+        self.addCleanup(server.stop)
+        session = RetrySession(SimpleServer.URL)
+        with self.assertLogs("qiskit_ibm_runtime.api.session", level="DEBUG") as logs:
+            session.get("/version")
+        timings = session.recent_requests[-1]["timings"]
+        self.assertGreater(timings["connect"], 0)
+        self.assertIsNone(timings["tls"])
+        self.assertGreaterEqual(timings["ttfb"], 0)
+        self.assertGreaterEqual(timings["total"], timings["connect"])
+        self.assertTrue(any("Timings of GET /version: connect=" in line for line in logs.output))
+        self.assertIn(
+            'qiskit_ibm_runtime_request_phase_duration_seconds_count{method="GET",'
+            'endpoint="/version",phase="connect"} 1',
+            session.metrics.to_prometheus(),
+        )
+
+    def test_proxy_env(self):
+        """Test proxies are read from the environment unless opted out of."""
+        session = RetrySession("https://dummy_url", proxies={"http": "http://explicit:8080"})
+        proxy_env = {"HTTPS_PROXY": "http://env_proxy:3128", "NO_PROXY": "internal.host"}
+        with custom_envs(proxy_env):
+            settings = session.merge_environment_settings("https://dummy_url", {}, None, None, None)
+            self.assertEqual(settings["proxies"]["https"], "http://env_proxy:3128")
+            settings = session.merge_environment_settings(
+                "https://internal.host", {}, None, None, None
+            )
+            self.assertNotIn("https", settings["proxies"])
+
+        with custom_envs({**proxy_env, PROXY_ENV_OPT_OUT_ENV_VAR: "True"}):
+            settings = session.merge_environment_settings("https://dummy_url", {}, None, None, None)
+            self.assertEqual(settings["proxies"], {"http": "http://explicit:8080"})
@@ -12,15 +12,54 @@
 
 """Tests for the RuntimeClient class."""
 
+import base64
+import hashlib
+import json
+import re
+import tempfile
+import threading
+from datetime import datetime, timezone
+from typing import Any, Dict
+from unittest.mock import patch
+
+from requests import Response
+
+from qiskit_ibm_runtime.api.cache_storage import (
+    DiskCacheStorage,
+    MemoryCacheStorage,
+    RedisCacheStorage,
+)
 from qiskit_ibm_runtime.api.client_parameters import ClientParameters
 from qiskit_ibm_runtime.api.clients import RuntimeClient
-from qiskit_ibm_runtime.api.exceptions import RequestsApiError
+from qiskit_ibm_runtime.api.exceptions import (
+    RequestsApiError,
+    RequestSchemaError,
+    ResultIntegrityError,
+)
+from qiskit_ibm_runtime.api.rest.utils.request_builders import (
+    JobRequestBuilder,
+    SessionRequestBuilder,
+)
+from qiskit_ibm_runtime.api.rest.utils.schema_validation import (
+    VALIDATE_REQUESTS_ENV_VAR,
+    validate_request_body,
+)
+from qiskit_ibm_runtime.exceptions import IBMInputValueError
+from qiskit_ibm_runtime.utils.usage_forecast import forecast_usage
 
 from .mock.http_server import SimpleServer, ClientErrorHandler
 from ..ibm_test_case import IBMTestCase
 from ..account import custom_envs, no_envs
 
 
+def _camel_case(data: Dict[str, Any]) -> Dict[str, Any]:
+    """Return the dictionary with its snake_case keys in camelCase."""
+    return {
+        re.sub(r"_([a-z])", lambda match: match.group(1).upper(), key): value
+        for key, value in data.items()
+    }
+
+
 class TestAccountClient(IBMTestCase):
     """Tests for RuntimeClient."""
 
@@ -85,3 +124,382 @@ class TestAccountClient(IBMTestCase):
             client._session.custom_header = None
             client._session._set_custom_header()
             self.assertNotIn(custom_header, client._session.headers["X-Qx-Client-Application"])
+
+    def test_job_results_checksum(self):
+        """Test job results are downloaded again if they do not match their checksum."""
+        content = b'{"results": [1, 2, 3]}'
+        checksum = base64.b64encode(hashlib.md5(content).digest()).decode("ascii")
+        corrupted = self._get_response(b'{"results": [1, 2, 4]}', checksum)
+        valid = self._get_response(content, checksum)
+
+        client = self._get_client()
+        with patch.object(client._session, "get", side_effect=[corrupted, valid]) as mock_get:
+            self.assertEqual(client.job_results("job_id"), content.decode())
+        self.assertEqual(mock_get.call_count, 2)
+
+        with patch.object(client._session, "get", side_effect=[corrupted, corrupted]):
+            with self.assertRaises(ResultIntegrityError):
+                client.job_results("job_id")
+
+    def test_warm_up(self):
+        """Test warming up the client sends a single authenticated request."""
+        client = self._get_client()
+        response = self._get_response(b'{"devices": []}', None)
+        with patch.object(client._session, "get", return_value=response) as mock_get:
+            client.warm_up()
+        mock_get.assert_called_once()
+        self.assertTrue(mock_get.call_args.args[0].endswith("/backends"))
+
+    def test_request_schema_validation(self):
+        """Test request bodies are validated against the API schemas in debug mode."""
+        client = self._get_client()
+        run_kwargs = {
+            "program_id": "sampler",
+            "backend_name": "ibm_gotham",
+            "params": {},
+            "image": None,
+            "hgp": None,
+            "log_level": "VERBOSE",
+            "session_id": None,
+        }
+        response = self._get_response(b'{"id": "job_id"}', None)
+        with patch.object(client._session, "post", return_value=response) as mock_post:
+            client.program_run(**run_kwargs)
+            self.assertEqual(mock_post.call_count, 1)
+
+            with custom_envs({VALIDATE_REQUESTS_ENV_VAR: "True"}):
+                with self.assertRaisesRegex(RequestSchemaError, "log_level"):
+                    client.program_run(**run_kwargs)
+                self.assertEqual(mock_post.call_count, 1)
+
+                run_kwargs["log_level"] = "INFO"
+                client.program_run(**run_kwargs)
+                self.assertEqual(mock_post.call_count, 2)
+
+                # Fields the schemas do not list are left for the server to check.
+                body = {"program_id": "sampler", "params": {}, "new_field": 1}
+                validate_request_body("job_create", body)
+
+    def test_submission_stats(self):
+        """Test reporting the size and encoding time of submitted payloads."""
+        client = self._get_client()
+        response = self._get_response(b'{"id": "job_id"}', None)
+        with patch.object(client._session, "post", return_value=response) as mock_post:
+            result = client.program_run(
+                program_id="sampler",
+                backend_name="ibm_gotham",
+                params={"pubs": [[], []], "version": 2},
+                image=None,
+                hgp=None,
+                log_level=None,
+                session_id=None,
+            )
+        stats = result["submission_stats"]
+        self.assertEqual(stats["num_pubs"], 2)
+        self.assertEqual(stats["num_circuits"], 0)
+        self.assertEqual(stats["payload_bytes"], len(mock_post.call_args[1]["data"]))
+        self.assertGreaterEqual(stats["encoding_time"], 0)
+
+    def test_response_field_casing(self):
+        """Test that camelCase fields of job and session responses are renamed to snake_case.
+
+        There are no captures of job or session responses in camelCase, so the camelCase
+        variants are derived from the snake_case responses.
+        """
+        job = {
+            "id": "job_id",
+            "state": {"status": "Failed", "reason_code": 1305},
+            "session_id": "session_id",
+            "user_id": "user_id",
+            "results_expiration": "2024-06-01T00:00:00Z",
+            "estimated_running_time_seconds": 1.5,
+        }
+        camel_job = _camel_case(job)
+        camel_job["state"] = _camel_case(job["state"])
+        session = {"id": "session_id", "state": "active", "accepting_jobs": True, "max_ttl": 60}
+        camel_session = _camel_case(session)
+        client = self._get_client()
+        subtests = [
+            ("job snake_case", lambda: client.job_get("job_id"), job, job),
+            ("job camelCase", lambda: client.job_get("job_id"), camel_job, job),
+            (
+                "jobs camelCase",
+                client.jobs_get,
+                {"jobs": [camel_job, job], "count": 2},
+                {"jobs": [job, job], "count": 2},
+            ),
+            ("session camelCase", lambda: client.session_details("id"), camel_session, session),
+            (
+                "sessions camelCase",
+                client.sessions_get,
+                {"sessions": [camel_session], "count": 1},
+                {"sessions": [session], "count": 1},
+            ),
+            # snake_case fields are kept if both casings are present.
+            (
+                "session mixed",
+                lambda: client.session_details("id"),
+                {**camel_session, **session},
+                {**session, "acceptingJobs": True, "maxTtl": 60},
+            ),
+        ]
+        for name, method, content, expected in subtests:
+            with self.subTest(name):
+                response = self._get_response(json.dumps(content).encode(), None)
+                with patch.object(client._session, "get", return_value=response):
+                    self.assertEqual(method(), expected)
+
+    def test_request_builders(self):
+        """Test building job and session creation request bodies."""
+        body = (
+            JobRequestBuilder(program_id="sampler", params={"version": 2})
+            .backend("ibm_gotham")
+            .tags(None)
+            .instance("hub", "group", None)
+            .start_session(60)
+            .build()
+        )
+        self.assertEqual(
+            body,
+            {
+                "program_id": "sampler",
+                "params": {"version": 2},
+                "backend": "ibm_gotham",
+                "start_session": True,
+                "session_time": 60,
+            },
+        )
+        with self.assertRaises(RequestSchemaError):
+            JobRequestBuilder(program_id="", params={})
+        with self.assertRaises(TypeError):
+            JobRequestBuilder("sampler", {})  # pylint: disable=too-many-function-args
+
+        self.assertEqual(
+            SessionRequestBuilder(channel="ibm_quantum").max_time(60).build(),
+            {"max_session_ttl": 60},
+        )
+        self.assertEqual(
+            SessionRequestBuilder(channel="ibm_cloud").mode("batch").max_time(60).build(),
+            {"mode": "batch", "max_ttl": 60},
+        )
+        with self.assertRaises(RequestSchemaError):
+            SessionRequestBuilder(channel="ibm_cloud").max_time(-1)
+
+    def test_metrics_text(self):
+        """Test exporting request metrics in the Prometheus text format."""
+        client = self._get_client()
+        response = self._get_response(b"{}", None)
+        with patch("requests.Session.request", return_value=response):
+            client.job_get("job1")
+            client.job_get("job2")
+        metrics = client.metrics_text()
+        self.assertIn(
+            'qiskit_ibm_runtime_requests_total{method="GET",endpoint="/jobs/{id}",status="200"} 2',
+            metrics,
+        )
+        self.assertIn(
+            "qiskit_ibm_runtime_request_duration_seconds_count"
+            '{method="GET",endpoint="/jobs/{id}"} 2',
+            metrics,
+        )
+        self.assertNotIn("job1", metrics)
+
+    def test_path_segments_encoded(self):
+        """Test that identifiers with special characters are percent-encoded in URLs."""
+        client = self._get_client()
+        response = self._get_response(b"{}", None)
+        subtests = [
+            ("job/../../backends", "/jobs/job%2F..%2F..%2Fbackends"),
+            ("job?x=1#y", "/jobs/job%3Fx%3D1%23y"),
+            ("jöb 1%", "/jobs/j%C3%B6b%201%25"),
+        ]
+        for job_id, path in subtests:
+            with self.subTest(job_id=job_id):
+                with patch("requests.Session.request", return_value=response) as mock_request:
+                    client.job_get(job_id)
+                self.assertTrue(mock_request.call_args[0][1].endswith(path))
+        for job_id in ["", "..", "job\n1"]:
+            with self.subTest(job_id=job_id):
+                with self.assertRaises(IBMInputValueError):
+                    client.job_get(job_id)
+
+    def test_shared_cache_storage(self):
+        """Test clients sharing a cache storage reuse the backend configuration and properties."""
+        with tempfile.TemporaryDirectory() as directory:
+            clients = []
+            for _ in range(2):
+                params = ClientParameters(
+                    channel="ibm_quantum",
+                    url=SimpleServer.URL,
+                    token="foo",
+                    instance="h/g/p",
+                    cache_storage=DiskCacheStorage(directory),
+                )
+                clients.append(RuntimeClient(params))
+            response = self._get_response(b'{"backend_name": "ibm_fake"}', None)
+            with patch("requests.Session.request", return_value=response) as mock_request:
+                clients[0].backend_configuration("ibm_fake")
+                clients[0].backend_properties("ibm_fake")
+                self.assertEqual(mock_request.call_count, 2)
+                self.assertEqual(
+                    clients[1].backend_configuration("ibm_fake"), {"backend_name": "ibm_fake"}
+                )
+                self.assertEqual(
+                    clients[1].backend_properties("ibm_fake"), {"backend_name": "ibm_fake"}
+                )
+                self.assertEqual(mock_request.call_count, 2)
+                clients[1].backend_properties("ibm_fake", refresh=True)
+                self.assertEqual(mock_request.call_count, 3)
+
+    def test_backend_snapshot(self):
+        """Test the backend configuration, properties and status are fetched concurrently."""
+        client = self._get_client()
+        # The requests only get past the barrier if all three are in flight at once.
+        barrier = threading.Barrier(3, timeout=5)
+        responses = {
+            "configuration": {"backend_name": "ibm_fake", "n_qubits": 5},
+            "properties": {"backend_name": "ibm_fake", "qubits": []},
+            "status": {"backend_version": "1.0.0", "state": True, "status": "active"},
+        }
+
+        def _request(_method, url, **_):
+            barrier.wait()
+            content = json.dumps(responses[url.rsplit("/", 1)[-1]]).encode()
+            return self._get_response(content, None)
+
+        with patch("requests.Session.request", side_effect=_request):
+            snapshot = client.backend_snapshot("ibm_fake")
+        self.assertEqual(snapshot["configuration"], responses["configuration"])
+        self.assertEqual(snapshot["properties"], responses["properties"])
+        self.assertTrue(snapshot["status"]["operational"])
+        self.assertEqual(snapshot["status"]["status_msg"], "active")
+
+    def test_backend_configurations(self):
+        """Test the configurations of several backends are fetched concurrently and cached."""
+        client = self._get_client()
+        names = ["ibm_gotham", "ibm_metropolis", "ibm_smallville"]
+        barrier = threading.Barrier(len(names), timeout=5)
+
+        def _request(_method, url, **_):
+            barrier.wait()
+            name = url.rsplit("/", 2)[-2]
+            return self._get_response(json.dumps({"backend_name": name}).encode(), None)
+
+        with patch("requests.Session.request", side_effect=_request) as mock_request:
+            configurations = client.backend_configurations(names)
+            self.assertEqual(configurations, {name: {"backend_name": name} for name in names})
+            client.backend_configuration("ibm_gotham")
+            self.assertEqual(mock_request.call_count, len(names))
+
+    def test_cache_storage_per_instance(self):
+        """Test clients of different instances do not share cached configurations."""
+        storage = MemoryCacheStorage()
+        clients = [
+            RuntimeClient(
+                ClientParameters(
+                    channel="ibm_quantum",
+                    url=SimpleServer.URL,
+                    token="foo",
+                    instance=instance,
+                    cache_storage=storage,
+                )
+            )
+            for instance in ("h/g/p1", "h/g/p2")
+        ]
+        response = self._get_response(b'{"backend_name": "ibm_fake"}', None)
+        with patch("requests.Session.request", return_value=response) as mock_request:
+            for client in clients:
+                client.backend_configuration("ibm_fake")
+            self.assertEqual(mock_request.call_count, 2)
+
+    def test_disk_cache_storage_malformed_entry(self):
+        """Test a malformed entry of the disk cache storage is a cache miss."""
+        with tempfile.TemporaryDirectory() as directory:
+            storage = DiskCacheStorage(directory)
+            for content in ['{"value": 1}', "[1, 2]", '{"expires_at": null, "val']:
+                with self.subTest(content=content):
+                    with open(storage._path("key"), "w", encoding="utf-8") as file:
+                        file.write(content)
+                    self.assertIsNone(storage.get("key"))
+            storage.set("key", {"value": 1})
+            self.assertEqual(storage.get("key"), {"value": 1})
+
+    def test_redis_cache_storage(self):
+        """Test the Redis cache storage with a fake Redis client."""
+
+        class FakeRedis:
+            """Fake Redis client storing the values in a dictionary."""
+
+            def __init__(self):
+                self.values = {}
+                self.expirations = {}
+
+            def get(self, key):
+                return self.values.get(key)
+
+            def set(self, key, value, ex=None):
+                self.values[key] = value.encode()
+                self.expirations[key] = ex
+
+            def delete(self, key):
+                self.values.pop(key, None)
+
+        client = FakeRedis()
+        storage = RedisCacheStorage(client=client, prefix="test:")
+        storage.set("key", {"value": 1}, ttl=1.5)
+        self.assertEqual(client.expirations, {"test:key": 2})
+        self.assertEqual(storage.get("key"), {"value": 1})
+        storage.delete("key")
+        self.assertIsNone(storage.get("key"))
+
+    def test_memory_cache_storage(self):
+        """Test values expire from the memory cache storage after their TTL."""
+        storage = MemoryCacheStorage()
+        storage.set("forever", 1)
+        storage.set("expired", 2, ttl=0)
+        self.assertEqual(storage.get("forever"), 1)
+        self.assertIsNone(storage.get("expired"))
+        storage.delete("forever")
+        self.assertIsNone(storage.get("forever"))
+
+    def test_forecast_usage(self):
+        """Test projecting the usage of an instance against its quota."""
+        usage = {
+            "usage_consumed_seconds": 300,
+            "usage_limit_seconds": 600,
+            "usage_period": {
+                "start_time": "2024-05-01T00:00:00Z",
+                "end_time": "2024-05-29T00:00:00Z",
+            },
+        }
+        client = self._get_client()
+        response = self._get_response(json.dumps(usage).encode(), None)
+        with patch("requests.Session.request", return_value=response) as mock_request:
+            self.assertEqual(client.usage(), usage)
+        self.assertTrue(mock_request.call_args.args[1].endswith("/usage"))
+
+        forecast = forecast_usage(usage, now=datetime(2024, 5, 11, tzinfo=timezone.utc))
+        self.assertEqual(forecast.remaining_seconds, 300)
+        self.assertEqual(forecast.daily_rate_seconds, 30)
+        self.assertEqual(forecast.projected_exhaustion, datetime(2024, 5, 21, tzinfo=timezone.utc))
+        self.assertAlmostEqual(forecast.daily_budget_seconds, 300 / 18)
+
+        forecast = forecast_usage(
+            usage, now=datetime(2024, 5, 11, tzinfo=timezone.utc), recent_daily_rate=60
+        )
+        self.assertEqual(forecast.daily_rate_seconds, 60)
+        self.assertEqual(forecast.projected_exhaustion, datetime(2024, 5, 16, tzinfo=timezone.utc))
+
+        forecast = forecast_usage({"usage_consumed_seconds": 300})
+        self.assertIsNone(forecast.limit_seconds)
+        self.assertIsNone(forecast.projected_exhaustion)
+        self.assertIsNone(forecast.daily_budget_seconds)
+
+    def _get_response(self, content, checksum):
+        """Return a response with the given content and Content-MD5 header."""
+        response = Response()
+        response.status_code = 200
+        response._content = content
+        if checksum:
+            response.headers["Content-MD5"] = checksum
+        return response
@@ -12,16 +12,27 @@
 
 """Tests for sampler class."""
 
-from unittest.mock import MagicMock
+from unittest.mock import MagicMock, patch
 
 from ddt import data, ddt, named_data
 import numpy as np
 
 from qiskit import QuantumCircuit, transpile, QuantumRegister, ClassicalRegister
+from qiskit.primitives.containers import BitArray, DataBin, PrimitiveResult, SamplerPubResult
 from qiskit.primitives.containers.sampler_pub import SamplerPub
 from qiskit.circuit.library import RealAmplitudes
-from qiskit_ibm_runtime import Sampler, Session, SamplerV2, SamplerOptions, IBMInputValueError
+from qiskit.utils import optionals
+from qiskit_ibm_runtime import (
+    EstimatorV2,
+    QiskitRuntimeService,
+    Sampler,
+    Session,
+    SamplerV2,
+    SamplerOptions,
+    IBMInputValueError,
+)
 from qiskit_ibm_runtime.fake_provider import FakeFractionalBackend
+from qiskit_ibm_runtime.utils.shot_splitting import merge_sampler_results, split_pub_indices
 
 from ..ibm_test_case import IBMTestCase
 from ..utils import bell, MockSession, dict_paritally_equal, get_mocked_backend, transpile_pubs
@@ -152,6 +163,45 @@ class TestSamplerV2(IBMTestCase):
                     f"{inputs} and {expected} not partially equal.",
                 )
 
+    def test_apply_profile(self):
+        """Test applying an option profile saved with the account."""
+        service = FakeRuntimeService(channel="ibm_quantum", token="abc")
+        service._account.option_profiles = {
+            "fast": {"default_shots": 1000, "execution": {"init_qubits": False}},
+            "too_many_shots": {"default_shots": 10**9},
+        }
+        inst = SamplerV2(mode=service.backend("common_backend"))
+        inst.apply_profile("fast")
+        self.assertEqual(inst.options.default_shots, 1000)
+        self.assertFalse(inst.options.execution.init_qubits)
+
+        with self.assertRaisesRegex(IBMInputValueError, "supports at most"):
+            inst.apply_profile("too_many_shots")
+        with self.assertRaisesRegex(IBMInputValueError, "Available profiles"):
+            inst.apply_profile("unknown")
+
+    def test_account_defaults(self):
+        """Test the default backend and options saved with the account are used."""
+        service = FakeRuntimeService(channel="ibm_quantum", token="abc")
+        service._account.default_backend = "common_backend"
+        service._account.default_options = {
+            "sampler": {"default_shots": 1000},
+            "estimator": {"default_precision": 0.05},
+        }
+        with patch.object(QiskitRuntimeService, "global_service", service):
+            inst = SamplerV2()
+            estimator = EstimatorV2()
+        self.assertEqual(inst._backend.name, "common_backend")
+        self.assertEqual(inst.options.default_shots, 1000)
+        self.assertEqual(estimator.options.default_precision, 0.05)
+
+        inst = SamplerV2(mode=service.backend(), options={"default_shots": 10})
+        self.assertEqual(inst._backend.name, "common_backend")
+        self.assertEqual(inst.options.default_shots, 10)
+
+        with Session(service=service) as session:
+            self.assertEqual(session.backend(), "common_backend")
+
     def test_sampler_validations(self):
         """Test exceptions when failing client-side validations."""
         with Session(
@@ -245,3 +295,66 @@ class TestSamplerV2(IBMTestCase):
         inst = SamplerV2(mode=backend)
         with self.assertRaises(IBMInputValueError):
             inst.run([dynamic_circuit, fractional_circuit])
+
+    def test_run_split_shots(self):
+        """Test pubs exceeding the maximum number of shots are split."""
+        backend = get_mocked_backend()
+        max_shots = backend.configuration().max_shots
+        self.assertEqual(max_shots, 100000)
+        circuit = transpile(bell(), backend=backend)
+        inst = SamplerV2(mode=backend)
+
+        inst.run([(circuit, None, 2 * max_shots + 1), circuit], shots=10)
+        pubs = backend.service.run.call_args.kwargs["inputs"]["pubs"]
+        self.assertEqual([pub.shots for pub in pubs], [2 * max_shots + 1, 10])
+
+        job = inst.run([(circuit, None, 2 * max_shots + 1), circuit], shots=10, split_shots=True)
+        pubs = backend.service.run.call_args.kwargs["inputs"]["pubs"]
+        self.assertEqual([pub.shots for pub in pubs], [66667, 66667, 66667, 10])
+        self.assertEqual(job._shots_splits, [[66667, 66667, 66667], [10]])
+
+    def test_run_openqasm3(self):
+        """Test running OpenQASM 3 programs."""
+        backend = get_mocked_backend()
+        inst = SamplerV2(mode=backend)
+        with self.assertRaisesRegex(IBMInputValueError, "empty"):
+            inst.run(["  "])
+        if not optionals.HAS_QASM3_IMPORT:
+            self.skipTest("qiskit-qasm3-import is required.")
+
+        program = """
+            OPENQASM 3.0;
+            include "stdgates.inc";
+            bit[2] c;
+            x $0;
+            cx $0, $1;
+            c[0] = measure $0;
+            c[1] = measure $1;
+        """
+        inst.run([program, (program, None, 10)], shots=20)
+        pubs = backend.service.run.call_args.kwargs["inputs"]["pubs"]
+        self.assertEqual([pub.shots for pub in pubs], [20, 10])
+        self.assertEqual(pubs[0].circuit.count_ops(), {"x": 1, "cx": 1, "measure": 2})
+
+        with self.assertRaisesRegex(IBMInputValueError, "Invalid OpenQASM 3 program"):
+            inst.run(["OPENQASM 3.0; qubit[2] q; not_a_gate q;"])
+
+    def test_merge_split_results(self):
+        """Test the results of split pubs are merged back together."""
+        splits = [[2, 1], [1]]
+        result = PrimitiveResult(
+            [
+                SamplerPubResult(DataBin(meas=BitArray.from_samples(["00", "11"], num_bits=2))),
+                SamplerPubResult(DataBin(meas=BitArray.from_samples(["11"], num_bits=2))),
+                SamplerPubResult(DataBin(meas=BitArray.from_samples(["01"], num_bits=2))),
+            ],
+            metadata={"version": 2},
+        )
+        merged = merge_sampler_results(result, splits)
+        self.assertEqual(len(merged), 2)
+        self.assertEqual(merged[0].data.meas.get_counts(), {"00": 1, "11": 2})
+        self.assertEqual(merged[0].metadata["shots_split"], [2, 1])
+        self.assertIs(merged[1], result[2])
+        self.assertEqual(merged.metadata, {"version": 2})
+
+        self.assertEqual(split_pub_indices(splits, [-1, 0]), ([2, 0, 1], [[1], [2, 1]]))
@@ -12,13 +12,17 @@
 
 """Tests for Session classession."""
 
+import time
+from datetime import datetime, timezone
 from unittest.mock import MagicMock, patch
 
 from qiskit_ibm_runtime.fake_provider import FakeManila
+from qiskit_ibm_runtime.fake_provider.local_service import QiskitRuntimeLocalService
 from qiskit_ibm_runtime import Session
 from qiskit_ibm_runtime.ibm_backend import IBMBackend
 from qiskit_ibm_runtime.exceptions import IBMRuntimeError
 from qiskit_ibm_runtime.utils.default_session import _DEFAULT_SESSION
+from qiskit_ibm_runtime.utils.session_state import SessionState
 
 from .mock.fake_runtime_service import FakeRuntimeService
 from ..ibm_test_case import IBMTestCase
@@ -153,6 +157,202 @@ class TestSession(IBMTestCase):
         session.run(program_id="foo", inputs={})
         self.assertEqual(session.session_id, session_id)
 
+    def test_invalid_max_time(self):
+        """Test non-positive max time is rejected."""
+        backend = get_mocked_backend(name="ibm_gotham")
+        for max_time in [0, -10]:
+            with self.subTest(max_time=max_time):
+                with self.assertRaises(ValueError):
+                    Session(service=backend.service, backend=backend, max_time=max_time)
+
+    def test_idle_timeout(self):
+        """Test an idle session is closed after a warning callback."""
+        backend = get_mocked_backend(name="ibm_gotham")
+        calls = []
+        session = Session(
+            service=backend.service,
+            backend=backend,
+            idle_timeout=1,
+            idle_callback=lambda session, remaining: calls.append(remaining),
+        )
+        deadline = time.monotonic() + 5
+        while session._active and time.monotonic() < deadline:
+            time.sleep(0.05)
+
+        self.assertFalse(session._active)
+        self.assertEqual(len(calls), 1)
+        self.assertLessEqual(calls[0], 0.2)
+        backend.service._api_client.close_session.assert_called_once()
+
+        backend.service._api_client.create_session.reset_mock()
+        with self.assertRaises(ValueError):
+            Session(service=backend.service, backend=backend, idle_timeout=0)
+        backend.service._api_client.create_session.assert_not_called()
+
+    def test_idle_timeout_without_server_session(self):
+        """Test the idle watcher is not started without a session on the server."""
+        simulator = get_mocked_backend(name="ibm_gotham")
+        simulator.configuration().simulator = True
+        for service, backend in [
+            (simulator.service, simulator),
+            (QiskitRuntimeLocalService(), "fake_manila"),
+        ]:
+            with self.subTest(backend=backend):
+                with patch("threading.Thread") as thread:
+                    session = Session(service=service, backend=backend, idle_timeout=60)
+                self.assertIsNone(session.session_id)
+                thread.assert_not_called()
+
+    def test_idle_callback_error(self):
+        """Test an exception in the idle callback does not stop the idle watcher."""
+        backend = get_mocked_backend(name="ibm_gotham")
+        session = Session(service=backend.service, backend=backend)
+
+        def _callback(*_):
+            raise RuntimeError("callback failed")
+
+        session._idle_timeout = 0.1
+        session._idle_callback = _callback
+        session._last_submission = time.monotonic()
+        with self.assertLogs("qiskit_ibm_runtime.session", level="ERROR") as logs:
+            session._watch_idle()
+        self.assertIn("idle callback", logs.output[0])
+        self.assertFalse(session._active)
+
+    def test_check_state(self):
+        """Test checking a session until it is closed by the server."""
+        backend = get_mocked_backend(name="ibm_gotham")
+        session_details = backend.service._api_client.session_details
+        session_details.side_effect = [{"state": "active"}, {"state": "closed"}]
+        with patch("threading.Thread") as thread:
+            session = Session(service=backend.service, backend=backend, state_check_interval=60)
+        thread.assert_called_once_with(target=session._check_state, daemon=True)
+        with patch.object(session._stop_watchers, "wait", return_value=False) as wait:
+            with self.assertLogs("qiskit_ibm_runtime.session", level="WARNING") as logs:
+                session._check_state()
+        wait.assert_called_with(60)
+        self.assertEqual(session_details.call_count, 2)
+        self.assertIn("closed by the server", logs.output[0])
+
+        session_details.reset_mock()
+        session._active = False
+        with patch.object(session._stop_watchers, "wait", return_value=False):
+            session._check_state()
+        session_details.assert_not_called()
+
+        backend.service._api_client.create_session.reset_mock()
+        with self.assertRaises(ValueError):
+            Session(service=backend.service, backend=backend, state_check_interval=0)
+        backend.service._api_client.create_session.assert_not_called()
+
+    def test_pause_resume(self):
+        """Test pausing and resuming job admission in a session."""
+        backend = get_mocked_backend(name="ibm_gotham")
+        update_session = backend.service._api_client.update_session
+        session = Session(service=backend.service, backend=backend)
+        session.pause()
+        update_session.assert_not_called()
+
+        session._session_id = "session_id"
+        session.pause()
+        update_session.assert_called_with("session_id", False)
+        session.resume()
+        update_session.assert_called_with("session_id", True)
+
+        session.close()
+        with self.assertRaises(IBMRuntimeError):
+            session.resume()
+
+    def test_fallback_backends(self):
+        """Test creating a session on the first operational backend of a list."""
+        down = get_mocked_backend(name="ibm_gotham")
+        down.status = MagicMock(return_value=MagicMock(operational=False))
+        up = get_mocked_backend(name="ibm_metropolis")
+        up.status = MagicMock(return_value=MagicMock(operational=True))
+
+        with self.assertLogs("qiskit_ibm_runtime.session", level="WARNING") as logs:
+            session = Session(service=down.service, backend=[down, up])
+        self.assertEqual(session.backend(), "ibm_metropolis")
+        self.assertIn("ibm_metropolis", logs.output[0])
+
+        up.status.return_value.operational = False
+        with self.assertRaises(ValueError):
+            Session(service=down.service, backend=[down, up])
+
+    def test_elapsed_time_and_remaining_ttl(self):
+        """Test the elapsed time and remaining TTL computed from the session details."""
+        now = datetime(2024, 5, 1, 12, 0, tzinfo=timezone.utc)
+        details = {"started_at": "2024-05-01T11:50:00Z", "max_ttl": 3600}
+        self.assertEqual(Session._session_times(details, now), (600, 3000))
+        details["closed_at"] = "2024-05-01T11:55:00Z"
+        self.assertEqual(Session._session_times(details, now), (300, 0))
+        self.assertEqual(Session._session_times({"max_ttl": 3600}, now), (None, None))
+
+        _ = FakeRuntimeService(channel="ibm_quantum", token="abc")
+        session = Session(backend="common_backend")
+        details = session.details()
+        self.assertIsNone(details["elapsed_time"])
+        self.assertIsNone(details["remaining_ttl"])
+
+    def test_list_sessions(self):
+        """Test listing the sessions of an instance page by page."""
+        service = FakeRuntimeService(channel="ibm_quantum", token="abc")
+        sessions = [{"id": f"session_{index}", "state": "active"} for index in range(25)]
+
+        def sessions_get(limit, skip, **_):
+            return {"sessions": sessions[skip : skip + min(limit, 20)], "count": len(sessions)}
+
+        with patch.object(service._api_client, "sessions_get", side_effect=sessions_get) as mock:
+            self.assertEqual(service.sessions(limit=None, pending=True), sessions)
+            self.assertEqual(mock.call_count, 2)
+            self.assertEqual(mock.call_args.kwargs["pending"], True)
+            self.assertEqual(service.sessions(limit=3, skip=22), sessions[22:])
+            self.assertEqual(service.sessions(limit=5).total_count, len(sessions))
+
+    def test_session_state(self):
+        """Test the transitions between session states."""
+        self.assertEqual(SessionState.parse("Pending_Closed"), SessionState.PENDING_CLOSED)
+        self.assertIsNone(SessionState.parse("unknown"))
+        self.assertTrue(SessionState.ACTIVE.can_transition_to(SessionState.INACTIVE))
+        self.assertFalse(SessionState.OPEN.can_transition_to(SessionState.INACTIVE))
+        self.assertTrue(SessionState.OPEN.can_reach(SessionState.INACTIVE))
+        self.assertFalse(SessionState.PENDING_CLOSED.can_reach(SessionState.ACTIVE))
+
+        backend = get_mocked_backend(name="ibm_gotham")
+        backend.service._api_client.session_details.side_effect = [
+            {"state": "active"},
+            {"state": "closed"},
+            {"state": "active"},
+        ]
+        session = Session(service=backend.service, backend=backend)
+        session._session_id = "session_id"
+        self.assertEqual(session.state(), SessionState.ACTIVE)
+        self.assertEqual(session.state(), SessionState.CLOSED)
+        with self.assertLogs("qiskit_ibm_runtime.session", level="WARNING") as logs:
+            self.assertEqual(session.state(), SessionState.ACTIVE)
+        self.assertIn("unexpectedly changed from state closed to active", logs.output[0])
+
+    def test_session_jobs(self):
+        """Test listing the jobs of a session and their statuses."""
+        service = FakeRuntimeService(channel="ibm_quantum", token="abc")
+        session = Session.from_id(session_id="session_id", service=service)
+        jobs_response = {
+            "jobs": [
+                {"id": "job_2", "state": {"status": "Running"}},
+                {"id": "job_1", "state": {"status": "Completed"}},
+            ],
+            "count": 2,
+        }
+        with patch.object(service._api_client, "jobs_get", return_value=jobs_response) as mock:
+            self.assertEqual(session.job_statuses(), {"job_2": "RUNNING", "job_1": "DONE"})
+        self.assertEqual(mock.call_args.kwargs["session_id"], "session_id")
+
+        with patch.object(service, "jobs") as mock_jobs:
+            self.assertEqual(session.jobs(limit=5), mock_jobs.return_value)
+        mock_jobs.assert_called_once_with(limit=5, session_id="session_id")
+
+        self.assertEqual(Session(service=MagicMock(), backend="ibm_gotham").job_statuses(), {})
+
     def test_correct_execution_mode(self):
         """Test that the execution mode is correctly set."""
         _ = FakeRuntimeService(channel="ibm_quantum", token="abc")